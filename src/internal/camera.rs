@@ -1,16 +1,237 @@
 use bevy::prelude::*;
 
+const MAX_SHAKE_OFFSET: f32 = 12.0;
+const MAX_SHAKE_ROTATION: f32 = 0.03;
+const TRAUMA_DECAY_PER_SECOND: f32 = 1.5;
+// Frequencies of the shake, mutually irrational so the motion doesn't repeat visibly
+const SHAKE_FREQUENCIES: [f32; 3] = [23.0, 29.7, 17.3];
+
 #[derive(Component)]
 pub struct GameCamera;
 
+/**
+ * Trauma based camera shake.
+ * Impacts add trauma (0..=1), which decays over time, the shake grows with the square of the trauma.
+ */
+#[derive(Component, Debug, Default)]
+pub struct CameraShake {
+    pub trauma: f32,
+}
+
+/// Camera effects the player can turn off in the main menu
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct CameraEffectsSettings {
+    pub shake: bool,
+    pub hit_stop: bool,
+}
+
+impl Default for CameraEffectsSettings {
+    fn default() -> Self {
+        Self {
+            shake: true,
+            hit_stop: true,
+        }
+    }
+}
+
+/// Shakes the camera by `trauma` and freezes the game for `hit_stop_seconds` of real time
+#[derive(Event, Debug, Clone, Copy, Default)]
+pub struct CameraImpact {
+    pub trauma: f32,
+    pub hit_stop_seconds: f32,
+}
+
+/// Real seconds left of the hit-stop, the virtual clock is paused meanwhile
+#[derive(Resource, Debug, Default)]
+pub struct HitStop(pub f32);
+
 pub struct CameraPlugin;
 
 impl Plugin for CameraPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Startup, setup);
+        app.add_event::<CameraImpact>()
+            .init_resource::<CameraEffectsSettings>()
+            .init_resource::<HitStop>()
+            .add_systems(Startup, setup)
+            .add_systems(
+                Update,
+                (handle_camera_impacts, update_hit_stop, shake_camera).chain(),
+            );
     }
 }
 
 fn setup(mut commands: Commands) {
-    commands.spawn((Camera2dBundle::default(), GameCamera));
+    commands.spawn((
+        Camera2dBundle::default(),
+        GameCamera,
+        CameraShake::default(),
+    ));
+}
+
+/// Offset and rotation of the camera for the given trauma at `seconds`
+pub fn shake_offset(trauma: f32, seconds: f32) -> (Vec2, f32) {
+    let shake = trauma.clamp(0.0, 1.0).powi(2);
+    let [x, y, rotation] = SHAKE_FREQUENCIES.map(|frequency| (seconds * frequency).sin());
+    (
+        Vec2::new(x, y) * MAX_SHAKE_OFFSET * shake,
+        rotation * MAX_SHAKE_ROTATION * shake,
+    )
+}
+
+fn handle_camera_impacts(
+    mut ev_impact: EventReader<CameraImpact>,
+    mut shake_query: Query<&mut CameraShake>,
+    mut hit_stop: ResMut<HitStop>,
+    mut virtual_time: ResMut<Time<Virtual>>,
+    settings: Res<CameraEffectsSettings>,
+) {
+    for impact in ev_impact.read() {
+        if settings.shake {
+            for mut camera_shake in &mut shake_query {
+                camera_shake.trauma = (camera_shake.trauma + impact.trauma).min(1.0);
+            }
+        }
+        if settings.hit_stop && impact.hit_stop_seconds > hit_stop.0 {
+            hit_stop.0 = impact.hit_stop_seconds;
+            virtual_time.pause();
+        }
+    }
+}
+
+fn update_hit_stop(
+    mut hit_stop: ResMut<HitStop>,
+    mut virtual_time: ResMut<Time<Virtual>>,
+    real_time: Res<Time<Real>>,
+) {
+    if hit_stop.0 <= 0.0 {
+        return;
+    }
+    hit_stop.0 -= real_time.delta_seconds();
+    if hit_stop.0 <= 0.0 {
+        hit_stop.0 = 0.0;
+        virtual_time.unpause();
+    }
+}
+
+/// Runs on real time, so the camera keeps shaking during hit-stop
+fn shake_camera(
+    mut query: Query<(&mut Transform, &mut CameraShake), With<GameCamera>>,
+    real_time: Res<Time<Real>>,
+) {
+    for (mut transform, mut camera_shake) in &mut query {
+        camera_shake.trauma =
+            (camera_shake.trauma - TRAUMA_DECAY_PER_SECOND * real_time.delta_seconds()).max(0.0);
+        let (offset, rotation) =
+            shake_offset(camera_shake.trauma, real_time.elapsed_seconds_wrapped());
+        transform.translation.x = offset.x;
+        transform.translation.y = offset.y;
+        transform.rotation = Quat::from_rotation_z(rotation);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    use rstest::rstest;
+
+    fn test_app(settings: CameraEffectsSettings) -> App {
+        let mut app = App::new();
+        app.add_plugins(CameraPlugin)
+            .insert_resource(settings)
+            .init_resource::<Time<Real>>()
+            .init_resource::<Time<Virtual>>();
+        app.update();
+        app
+    }
+
+    fn advance(app: &mut App, seconds: f32) {
+        let mut real_time = app.world.resource_mut::<Time<Real>>();
+        let last_update = real_time.last_update().unwrap_or(real_time.startup());
+        real_time.update_with_instant(last_update + Duration::from_secs_f32(seconds));
+        app.update();
+    }
+
+    fn trauma(app: &mut App) -> f32 {
+        app.world.query::<&CameraShake>().single(&app.world).trauma
+    }
+
+    fn paused(app: &App) -> bool {
+        app.world.resource::<Time<Virtual>>().is_paused()
+    }
+
+    #[rstest]
+    #[case(0.0, 0.0)]
+    #[case(0.5, 0.25)]
+    #[case(1.0, 1.0)]
+    #[case(2.0, 1.0)]
+    fn test_shake_offset_grows_with_trauma_squared(#[case] trauma: f32, #[case] shake: f32) {
+        for step in 0..100 {
+            let (offset, rotation) = shake_offset(trauma, step as f32 * 0.01);
+
+            assert!(offset.x.abs() <= MAX_SHAKE_OFFSET * shake + 1e-5);
+            assert!(offset.y.abs() <= MAX_SHAKE_OFFSET * shake + 1e-5);
+            assert!(rotation.abs() <= MAX_SHAKE_ROTATION * shake + 1e-5);
+        }
+    }
+
+    #[test]
+    fn test_trauma_decays_to_zero() {
+        let mut app = test_app(CameraEffectsSettings::default());
+        app.world.send_event(CameraImpact {
+            trauma: 0.6,
+            ..default()
+        });
+
+        advance(&mut app, 0.0);
+        assert_eq!(trauma(&mut app), 0.6);
+        advance(&mut app, 0.2);
+        assert!((trauma(&mut app) - 0.3).abs() < 1e-5);
+        advance(&mut app, 0.3);
+        assert_eq!(trauma(&mut app), 0.0);
+
+        let transform = app
+            .world
+            .query_filtered::<&Transform, With<GameCamera>>()
+            .single(&app.world);
+        assert_eq!(transform.translation.truncate(), Vec2::ZERO);
+        assert_eq!(transform.rotation, Quat::IDENTITY);
+    }
+
+    #[test]
+    fn test_hit_stop_pauses_virtual_time() {
+        let mut app = test_app(CameraEffectsSettings::default());
+        app.world.send_event(CameraImpact {
+            hit_stop_seconds: 0.05,
+            ..default()
+        });
+
+        advance(&mut app, 0.0);
+        assert!(paused(&app));
+        advance(&mut app, 0.03);
+        assert!(paused(&app));
+        advance(&mut app, 0.03);
+        assert!(!paused(&app));
+        assert_eq!(app.world.resource::<HitStop>().0, 0.0);
+    }
+
+    #[rstest]
+    #[case(true, true)]
+    #[case(true, false)]
+    #[case(false, true)]
+    #[case(false, false)]
+    fn test_settings_turn_effects_off(#[case] shake: bool, #[case] hit_stop: bool) {
+        let mut app = test_app(CameraEffectsSettings { shake, hit_stop });
+        app.world.send_event(CameraImpact {
+            trauma: 0.5,
+            hit_stop_seconds: 0.1,
+        });
+
+        advance(&mut app, 0.0);
+
+        assert_eq!(trauma(&mut app) > 0.0, shake);
+        assert_eq!(paused(&app), hit_stop);
+    }
 }
@@ -0,0 +1,361 @@
+use std::collections::VecDeque;
+
+use bevy::{prelude::*, transform::TransformSystem};
+
+const POPUP_SECONDS: f32 = 0.8;
+const POPUP_RISE_SPEED: f32 = 60.0;
+const POPUP_FONT_SIZE: f32 = 24.0;
+const POPUP_COLOR: Color = Color::rgb(1.0, 0.5, 0.5);
+
+const PARTICLE_SECONDS: f32 = 0.5;
+const PARTICLE_SIZE: f32 = 5.0;
+const PARTICLE_MIN_SPEED: f32 = 80.0;
+const PARTICLE_MAX_SPEED: f32 = 220.0;
+const PARTICLE_GRAVITY: f32 = -400.0;
+
+const FLASH_SECONDS: f32 = 0.12;
+const FLASH_GROWTH: f32 = 1.2;
+
+// Effects render above the playfield
+const EFFECT_Z: f32 = 5.0;
+
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct TrailSettings {
+    pub enabled: bool,
+    /// Seconds of recent positions a trail is drawn through, independent of the framerate
+    pub duration_seconds: f32,
+}
+
+impl Default for TrailSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            duration_seconds: 0.2,
+        }
+    }
+}
+
+/// Fading line through the recent positions of an entity, drawn with gizmos
+#[derive(Component, Debug, Clone)]
+pub struct Trail {
+    pub color: Color,
+    /// Positions with the time they were recorded at
+    points: VecDeque<(Vec2, f32)>,
+}
+
+impl Trail {
+    pub fn new(color: Color) -> Self {
+        Self {
+            color,
+            points: VecDeque::new(),
+        }
+    }
+
+    /// Records `point` at `seconds` and drops the points older than `duration_seconds`
+    pub fn push(&mut self, point: Vec2, seconds: f32, duration_seconds: f32) {
+        // A paused clock would pile up points at the same time
+        if self.points.back().is_some_and(|&(_, last)| last >= seconds) {
+            self.points.pop_back();
+        }
+        self.points.push_back((point, seconds));
+        while self
+            .points
+            .front()
+            .is_some_and(|&(_, recorded)| seconds - recorded > duration_seconds)
+        {
+            self.points.pop_front();
+        }
+    }
+
+    /// Points from oldest to newest, with their age in seconds
+    pub fn points(&self, seconds: f32) -> impl Iterator<Item = (Vec2, f32)> + '_ {
+        self.points
+            .iter()
+            .map(move |&(point, recorded)| (point, seconds - recorded))
+    }
+
+    pub fn clear(&mut self) {
+        self.points.clear();
+    }
+}
+
+#[derive(Event, Debug, Clone, Copy)]
+pub enum EffectEvent {
+    /// Floating "+N" text that rises and fades
+    ScorePopup { position: Vec2, points: usize },
+    /// Burst of small squares flying out of `position`
+    Particles {
+        position: Vec2,
+        color: Color,
+        count: usize,
+    },
+    /// Short bright flash covering a rectangle
+    Flash {
+        position: Vec2,
+        size: Vec2,
+        color: Color,
+    },
+}
+
+/**
+ * Short lived visual effects, spawned by sending `EffectEvent`s.
+ * Effects despawn themselves, so they need no cleanup from the state that spawned them.
+ */
+pub struct EffectsPlugin;
+
+impl Plugin for EffectsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<EffectEvent>()
+            .init_resource::<TrailSettings>()
+            .add_systems(
+                Update,
+                (
+                    spawn_effects,
+                    (update_score_popups, update_particles, update_flashes),
+                )
+                    .chain(),
+            )
+            .add_systems(
+                PostUpdate,
+                draw_trails.after(TransformSystem::TransformPropagate),
+            );
+    }
+}
+
+#[derive(Component)]
+struct ScorePopup {
+    timer: Timer,
+}
+
+#[derive(Component)]
+struct Particle {
+    velocity: Vec2,
+    color: Color,
+    timer: Timer,
+}
+
+#[derive(Component)]
+struct Flash {
+    color: Color,
+    size: Vec2,
+    timer: Timer,
+}
+
+/// Spreads particles evenly around the circle, varying their speed so the burst doesn't look like a ring
+fn particle_velocity(index: usize, count: usize) -> Vec2 {
+    let angle = index as f32 / count.max(1) as f32 * std::f32::consts::TAU;
+    // Golden ratio sequence, evenly distributed speeds without a random number generator
+    let spread = (index as f32 * 0.618_034).fract();
+    Vec2::from_angle(angle)
+        * (PARTICLE_MIN_SPEED + (PARTICLE_MAX_SPEED - PARTICLE_MIN_SPEED) * spread)
+}
+
+fn spawn_effects(mut commands: Commands, mut ev_effect: EventReader<EffectEvent>) {
+    for effect in ev_effect.read() {
+        match *effect {
+            EffectEvent::ScorePopup { position, points } => {
+                commands.spawn((
+                    Text2dBundle {
+                        text: Text::from_section(
+                            format!("+{points}"),
+                            TextStyle {
+                                font_size: POPUP_FONT_SIZE,
+                                color: POPUP_COLOR,
+                                ..default()
+                            },
+                        ),
+                        transform: Transform::from_translation(position.extend(EFFECT_Z)),
+                        ..default()
+                    },
+                    ScorePopup {
+                        timer: Timer::from_seconds(POPUP_SECONDS, TimerMode::Once),
+                    },
+                ));
+            }
+            EffectEvent::Particles {
+                position,
+                color,
+                count,
+            } => {
+                for index in 0..count {
+                    commands.spawn((
+                        SpriteBundle {
+                            sprite: Sprite { color, ..default() },
+                            transform: Transform {
+                                translation: position.extend(EFFECT_Z),
+                                scale: Vec3::new(PARTICLE_SIZE, PARTICLE_SIZE, 1.0),
+                                ..default()
+                            },
+                            ..default()
+                        },
+                        Particle {
+                            velocity: particle_velocity(index, count),
+                            color,
+                            timer: Timer::from_seconds(PARTICLE_SECONDS, TimerMode::Once),
+                        },
+                    ));
+                }
+            }
+            EffectEvent::Flash {
+                position,
+                size,
+                color,
+            } => {
+                commands.spawn((
+                    SpriteBundle {
+                        sprite: Sprite { color, ..default() },
+                        transform: Transform {
+                            translation: position.extend(EFFECT_Z),
+                            scale: size.extend(1.0),
+                            ..default()
+                        },
+                        ..default()
+                    },
+                    Flash {
+                        color,
+                        size,
+                        timer: Timer::from_seconds(FLASH_SECONDS, TimerMode::Once),
+                    },
+                ));
+            }
+        }
+    }
+}
+
+fn update_score_popups(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut ScorePopup, &mut Transform, &mut Text)>,
+    time: Res<Time>,
+) {
+    for (entity, mut popup, mut transform, mut text) in &mut query {
+        if popup.timer.tick(time.delta()).finished() {
+            commands.entity(entity).despawn_recursive();
+            continue;
+        }
+        transform.translation.y += POPUP_RISE_SPEED * time.delta_seconds();
+        for section in &mut text.sections {
+            section.style.color = POPUP_COLOR.with_a(1.0 - popup.timer.percent());
+        }
+    }
+}
+
+fn update_particles(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut Particle, &mut Transform, &mut Sprite)>,
+    time: Res<Time>,
+) {
+    for (entity, mut particle, mut transform, mut sprite) in &mut query {
+        if particle.timer.tick(time.delta()).finished() {
+            commands.entity(entity).despawn_recursive();
+            continue;
+        }
+        particle.velocity.y += PARTICLE_GRAVITY * time.delta_seconds();
+        transform.translation += (particle.velocity * time.delta_seconds()).extend(0.0);
+        sprite.color = particle.color.with_a(1.0 - particle.timer.percent());
+    }
+}
+
+fn update_flashes(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut Flash, &mut Transform, &mut Sprite)>,
+    time: Res<Time>,
+) {
+    for (entity, mut flash, mut transform, mut sprite) in &mut query {
+        if flash.timer.tick(time.delta()).finished() {
+            commands.entity(entity).despawn_recursive();
+            continue;
+        }
+        let progress = flash.timer.percent();
+        transform.scale = (flash.size * (1.0 + (FLASH_GROWTH - 1.0) * progress)).extend(1.0);
+        sprite.color = flash.color.with_a(1.0 - progress);
+    }
+}
+
+/// Records the rendered (interpolated) positions, so trails stay smooth between physics ticks
+fn draw_trails(
+    mut gizmos: Gizmos,
+    mut query: Query<(&mut Trail, &GlobalTransform)>,
+    settings: Res<TrailSettings>,
+    time: Res<Time>,
+) {
+    let seconds = time.elapsed_seconds();
+    let duration_seconds = settings.duration_seconds;
+    for (mut trail, transform) in &mut query {
+        if !settings.enabled {
+            trail.clear();
+            continue;
+        }
+        trail.push(
+            transform.translation().truncate(),
+            seconds,
+            duration_seconds,
+        );
+
+        let color = trail.color;
+        gizmos.linestrip_gradient_2d(trail.points(seconds).map(|(point, age)| {
+            (
+                point,
+                color.with_a((1.0 - age / duration_seconds).clamp(0.0, 1.0)),
+            )
+        }));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(60.0)]
+    #[case(120.0)]
+    fn test_trail_keeps_recent_seconds(#[case] framerate: f32) {
+        let mut trail = Trail::new(Color::WHITE);
+        let frames = framerate as usize;
+        for frame in 0..=frames {
+            let seconds = frame as f32 / framerate;
+            // Just over a quarter second, so float rounding doesn't decide the oldest point
+            trail.push(Vec2::new(seconds, 0.0), seconds, 0.255);
+        }
+
+        let points: Vec<_> = trail.points(1.0).collect();
+        assert_eq!(points.len(), frames / 4 + 1);
+        assert!(points
+            .iter()
+            .all(|&(point, age)| age <= 0.255 && (point.x + age - 1.0).abs() < 1e-5));
+    }
+
+    #[test]
+    fn test_trail_does_not_grow_on_paused_clock() {
+        let mut trail = Trail::new(Color::WHITE);
+        for index in 0..10 {
+            trail.push(Vec2::new(index as f32, 0.0), 1.0, 0.25);
+        }
+
+        assert_eq!(
+            trail.points(1.0).collect::<Vec<_>>(),
+            [(Vec2::new(9.0, 0.0), 0.0)]
+        );
+    }
+
+    #[test]
+    fn test_particle_velocity_spread() {
+        let count = 12;
+        let velocities: Vec<_> = (0..count)
+            .map(|index| particle_velocity(index, count))
+            .collect();
+
+        for velocity in &velocities {
+            let speed = velocity.length();
+            assert!((PARTICLE_MIN_SPEED..=PARTICLE_MAX_SPEED).contains(&speed));
+        }
+        // The burst is balanced around its origin
+        let average = velocities
+            .iter()
+            .map(|velocity| velocity.normalize())
+            .sum::<Vec2>()
+            / count as f32;
+        assert!(average.length() < 1e-3);
+    }
+}
@@ -0,0 +1 @@
+pub mod effects;
\ No newline at end of file
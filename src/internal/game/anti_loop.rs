@@ -0,0 +1,137 @@
+use bevy::{prelude::*, utils::HashSet};
+
+use crate::internal::physics::{GameTime, Velocity};
+
+use super::{
+    ball::Ball,
+    catch::CaughtBall,
+    gameplay_events::{BrickHit, PaddleHit},
+    level::Level,
+};
+
+#[derive(Debug, Clone, Copy)]
+pub struct AntiLoopPolicy {
+    /// Seconds without touching a brick or the paddle before a trajectory is considered looping
+    pub timeout_seconds: f32,
+    /// How close to the horizontal or vertical axis (in radians) a looping trajectory is
+    pub axis_threshold: f32,
+    /// Angle (in radians) the ball is turned away from the axis
+    pub nudge_angle: f32,
+}
+
+impl Default for AntiLoopPolicy {
+    fn default() -> Self {
+        Self {
+            timeout_seconds: 5.0,
+            axis_threshold: 10f32.to_radians(),
+            nudge_angle: 15f32.to_radians(),
+        }
+    }
+}
+
+/// Seconds since the ball last touched a brick or the paddle
+#[derive(Component, Default)]
+pub struct LoopGuard(pub f32);
+
+/**
+ * Turns a velocity close to the horizontal or vertical axis towards the diagonal.
+ * Horizontal trajectories are pushed downwards when they have no vertical component,
+ * so the ball comes back to the paddle.
+ */
+pub fn nudge_trajectory(velocity: Vec2, axis_threshold: f32, nudge_angle: f32) -> Option<Vec2> {
+    let speed = velocity.length();
+    if speed == 0.0 {
+        return None;
+    }
+
+    // Angle from the horizontal axis, folded into the first quadrant
+    let angle = velocity.y.abs().atan2(velocity.x.abs());
+    let new_angle = if angle < axis_threshold {
+        angle + nudge_angle
+    } else if angle > std::f32::consts::FRAC_PI_2 - axis_threshold {
+        angle - nudge_angle
+    } else {
+        return None;
+    };
+
+    let sign_x = if velocity.x == 0.0 {
+        1.0
+    } else {
+        velocity.x.signum()
+    };
+    let sign_y = if velocity.y == 0.0 {
+        -1.0
+    } else {
+        velocity.y.signum()
+    };
+    Some(Vec2::new(new_angle.cos() * sign_x, new_angle.sin() * sign_y) * speed)
+}
+
+pub fn handle_loop_guard(
+    mut ev_brick_hit: EventReader<BrickHit>,
+    mut ev_paddle_hit: EventReader<PaddleHit>,
+    mut ball_query: Query<
+        (Entity, &mut Velocity, &mut LoopGuard),
+        (With<Ball>, Without<CaughtBall>),
+    >,
+    level: Res<Level>,
+    time: GameTime,
+) {
+    let mut progressed = HashSet::<Entity>::new();
+    progressed.extend(ev_brick_hit.read().map(|brick_hit| brick_hit.by));
+    progressed.extend(ev_paddle_hit.read().map(|paddle_hit| paddle_hit.ball));
+
+    let policy = &level.anti_loop;
+    for (entity, mut velocity, mut loop_guard) in &mut ball_query {
+        if progressed.contains(&entity) {
+            loop_guard.0 = 0.0;
+            continue;
+        }
+
+        loop_guard.0 += time.delta_seconds();
+        if loop_guard.0 < policy.timeout_seconds {
+            continue;
+        }
+
+        if let Some(new_velocity) =
+            nudge_trajectory(velocity.0, policy.axis_threshold, policy.nudge_angle)
+        {
+            velocity.0 = new_velocity;
+            loop_guard.0 = 0.0;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rstest::rstest;
+
+    const THRESHOLD: f32 = 0.2;
+    const NUDGE: f32 = 0.3;
+
+    #[rstest]
+    #[case(Vec2::new(100.0, 100.0), None)]
+    #[case(Vec2::ZERO, None)]
+    #[case(
+        Vec2::new(100.0, 0.0),
+        Some(Vec2::new(NUDGE.cos(), -NUDGE.sin()) * 100.0)
+    )]
+    #[case(
+        Vec2::new(-100.0, 0.0),
+        Some(Vec2::new(-NUDGE.cos(), -NUDGE.sin()) * 100.0)
+    )]
+    #[case(
+        Vec2::new(0.0, 100.0),
+        Some(Vec2::new((std::f32::consts::FRAC_PI_2 - NUDGE).cos(), (std::f32::consts::FRAC_PI_2 - NUDGE).sin()) * 100.0)
+    )]
+    fn test_nudge_trajectory(#[case] velocity: Vec2, #[case] expected: Option<Vec2>) {
+        let nudged = nudge_trajectory(velocity, THRESHOLD, NUDGE);
+
+        match (nudged, expected) {
+            (Some(nudged), Some(expected)) => assert!(nudged.abs_diff_eq(expected, 1e-3)),
+            (nudged, expected) => assert_eq!(nudged, expected),
+        }
+    }
+}
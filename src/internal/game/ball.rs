@@ -0,0 +1,109 @@
+use bevy::{prelude::*, sprite::MaterialMesh2dBundle};
+
+use crate::internal::{
+    effects::effects::Trail,
+    physics::{
+        AngularVelocity, CollisionBody, CollisionGroup, CollisionMask, CollisionProperties,
+        RigidBody, SpeedLimit, SpinEffect, TransformInterpolation, Velocity,
+    },
+};
+
+use super::{
+    anti_loop::LoopGuard, catch::CaughtBall, fireball::Fireball, power_up::PowerUpKind,
+    slow_motion::SlowBall, wall::BOTTOM_WALL,
+};
+
+// We set the z-value of the ball to 1 so it renders on top in the case of overlapping sprites.
+pub const BALL_SIZE: Vec3 = Vec3::new(20.0, 20.0, 0.0);
+pub const BALL_STARTING_POSITION: Vec3 = Vec3::new(0.0, BOTTOM_WALL + 60. + 20. + 20., 1.0);
+pub const INITIAL_BALL_DIRECTION: Vec2 = Vec2::new(0.5, 0.5);
+
+pub const BALL_COLOR: Color = Color::rgb(1.0, 0.5, 0.5);
+// Off-center mark so the ball's rotation is visible
+const BALL_SPIN_MARK_COLOR: Color = Color::rgb(0.6, 0.2, 0.2);
+const BALL_FRICTION: f32 = 1.0;
+const BALL_SPIN_CURVE: f32 = 0.03;
+const BALL_SPIN_DAMPING: f32 = 0.3;
+
+#[derive(Component)]
+pub struct Ball;
+
+pub struct BallFactory {
+    pub position: Vec3,
+    pub velocity: Vec2,
+    pub speed_limit: SpeedLimit,
+}
+
+impl BallFactory {
+    pub fn spawn(
+        &self,
+        parent: &mut ChildBuilder,
+        meshes: &mut Assets<Mesh>,
+        materials: &mut Assets<ColorMaterial>,
+    ) {
+        parent
+            .spawn((
+                MaterialMesh2dBundle {
+                    mesh: meshes.add(shape::Circle::default().into()).into(),
+                    material: materials.add(ColorMaterial::from(BALL_COLOR)),
+                    transform: Transform::from_translation(self.position).with_scale(BALL_SIZE),
+                    ..default()
+                },
+                Ball,
+                Trail::new(BALL_COLOR),
+                LoopGuard::default(),
+                RigidBody::Dynamic,
+                Velocity(self.velocity),
+                self.speed_limit,
+                AngularVelocity::default(),
+                SpinEffect {
+                    curve: BALL_SPIN_CURVE,
+                    damping: BALL_SPIN_DAMPING,
+                },
+                TransformInterpolation::default(),
+                CollisionProperties {
+                    body: CollisionBody::Circle,
+                    group: CollisionMask::new(&[CollisionGroup::Ball]),
+                    mask: CollisionMask::new(&[
+                        CollisionGroup::Block,
+                        CollisionGroup::Wall,
+                        CollisionGroup::Paddle,
+                    ]),
+                    friction: BALL_FRICTION,
+                    ..default()
+                },
+            ))
+            .with_children(|ball| {
+                // Child transforms are relative to the unit circle scaled by `BALL_SIZE`
+                ball.spawn(SpriteBundle {
+                    sprite: Sprite {
+                        color: BALL_SPIN_MARK_COLOR,
+                        ..default()
+                    },
+                    transform: Transform {
+                        translation: Vec3::new(0.25, 0.0, 0.1),
+                        scale: Vec3::new(0.3, 0.1, 1.0),
+                        ..default()
+                    },
+                    ..default()
+                });
+            });
+    }
+}
+
+/// Colors the trail by the ball's state, the colors match the power-ups causing them
+pub fn update_ball_trails(
+    mut query: Query<(&mut Trail, Has<Fireball>, Has<CaughtBall>, Has<SlowBall>), With<Ball>>,
+) {
+    for (mut trail, fireball, caught, slow) in &mut query {
+        trail.color = if fireball {
+            PowerUpKind::Fireball.color()
+        } else if caught {
+            PowerUpKind::Catch.color()
+        } else if slow {
+            PowerUpKind::SlowBall.color()
+        } else {
+            BALL_COLOR
+        };
+    }
+}
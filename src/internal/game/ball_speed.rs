@@ -0,0 +1,75 @@
+use bevy::prelude::*;
+
+use crate::internal::physics::{SpeedLimit, Velocity};
+
+use super::{
+    ball::Ball,
+    gameplay_events::{BallLost, BrickDestroyCause, BrickDestroyed, PaddleHit},
+    level::Level,
+};
+
+#[derive(Debug, Clone, Copy)]
+pub struct BallSpeedPolicy {
+    pub starting_speed: f32,
+    pub min_speed: f32,
+    pub max_speed: f32,
+    pub speed_up_per_paddle_hit: f32,
+    pub speed_up_per_brick: f32,
+}
+
+impl Default for BallSpeedPolicy {
+    fn default() -> Self {
+        Self {
+            starting_speed: 200.0,
+            min_speed: 150.0,
+            max_speed: 600.0,
+            speed_up_per_paddle_hit: 5.0,
+            speed_up_per_brick: 2.0,
+        }
+    }
+}
+
+/// The speed balls are currently kept at or above, raised as the player hits the paddle and breaks bricks
+#[derive(Resource, Deref, DerefMut)]
+pub struct BallSpeed(pub f32);
+
+pub fn sync_ball_speed_limits(
+    ball_speed: Res<BallSpeed>,
+    level: Res<Level>,
+    mut query: Query<&mut SpeedLimit, With<Ball>>,
+) {
+    let policy = &level.ball_speed;
+    for mut speed_limit in &mut query {
+        speed_limit.min = ball_speed.0.clamp(policy.min_speed, policy.max_speed);
+        speed_limit.max = policy.max_speed;
+    }
+}
+
+pub fn handle_ball_speed_events(
+    mut ev_paddle_hit: EventReader<PaddleHit>,
+    mut ev_brick_destroyed: EventReader<BrickDestroyed>,
+    mut ev_ball_lost: EventReader<BallLost>,
+    mut ball_speed: ResMut<BallSpeed>,
+    level: Res<Level>,
+    mut ball_query: Query<&mut Velocity, With<Ball>>,
+) {
+    let policy = &level.ball_speed;
+    for _ in ev_paddle_hit.read() {
+        ball_speed.0 += policy.speed_up_per_paddle_hit;
+    }
+    for brick in ev_brick_destroyed.read() {
+        if let BrickDestroyCause::Hit { by, .. } = brick.cause {
+            if ball_query.contains(by) {
+                ball_speed.0 += policy.speed_up_per_brick;
+            }
+        }
+    }
+    ball_speed.0 = ball_speed.0.clamp(policy.min_speed, policy.max_speed);
+
+    if ev_ball_lost.read().count() > 0 {
+        ball_speed.0 = policy.starting_speed;
+        for mut velocity in &mut ball_query {
+            velocity.0 = velocity.normalize_or_zero() * policy.starting_speed;
+        }
+    }
+}
@@ -0,0 +1,194 @@
+use bevy::prelude::*;
+
+use crate::internal::physics::{
+    CollisionBody, CollisionGroup, CollisionMask, CollisionProperties, GameTime,
+};
+
+use super::{
+    game::GameState,
+    gameplay_events::{PowerUpCollected, WallHit},
+    level::Level,
+    power_up::PowerUpKind,
+    wall::{BOTTOM_WALL, LEFT_WALL, RIGHT_WALL, WALL_THICKNESS},
+};
+
+const BARRIER_COLOR: Color = Color::rgb(0.5, 0.9, 0.9);
+const BARRIER_THICKNESS: f32 = 6.0;
+// Gap between the barrier and the floor, so balls bounce before they reach the floor
+const BARRIER_GAP: f32 = 10.0;
+
+#[derive(Debug, Clone, Copy)]
+pub struct BarrierPolicy {
+    pub duration_seconds: f32,
+    /// How many times the barrier bounces a ball back before it breaks
+    pub saves: u32,
+    /// The barrier flickers for the last seconds, or when it has a single save left
+    pub warning_seconds: f32,
+    pub flicker_hz: f32,
+}
+
+impl Default for BarrierPolicy {
+    fn default() -> Self {
+        Self {
+            duration_seconds: 15.0,
+            saves: 3,
+            warning_seconds: 3.0,
+            flicker_hz: 6.0,
+        }
+    }
+}
+
+/// Temporary wall above the floor, lasting until it runs out of time or saves
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct Barrier {
+    pub remaining_seconds: f32,
+    pub remaining_saves: u32,
+}
+
+impl Barrier {
+    pub fn new(policy: &BarrierPolicy) -> Self {
+        Self {
+            remaining_seconds: policy.duration_seconds,
+            remaining_saves: policy.saves,
+        }
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.remaining_seconds <= 0.0 || self.remaining_saves == 0
+    }
+
+    pub fn is_visible(&self, policy: &BarrierPolicy) -> bool {
+        if self.remaining_seconds > policy.warning_seconds && self.remaining_saves > 1 {
+            return true;
+        }
+        (self.remaining_seconds * policy.flicker_hz * 2.0).floor() as i32 % 2 == 0
+    }
+}
+
+pub struct BarrierFactory {
+    pub barrier: Barrier,
+}
+
+impl BarrierFactory {
+    pub fn spawn(&self, commands: &mut Commands) {
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: BARRIER_COLOR,
+                    ..default()
+                },
+                transform: Transform {
+                    translation: Vec3::new(
+                        0.0,
+                        BOTTOM_WALL + (WALL_THICKNESS + BARRIER_THICKNESS) / 2.0 + BARRIER_GAP,
+                        0.0,
+                    ),
+                    scale: Vec3::new(
+                        RIGHT_WALL - LEFT_WALL - WALL_THICKNESS,
+                        BARRIER_THICKNESS,
+                        1.0,
+                    ),
+                    ..default()
+                },
+                ..default()
+            },
+            self.barrier,
+            GameState,
+            CollisionProperties {
+                body: CollisionBody::Rect,
+                group: CollisionMask::new(&[CollisionGroup::Wall]),
+                // Power-ups fall through to the floor
+                mask: CollisionMask::new(&[CollisionGroup::Ball]),
+                ..default()
+            },
+        ));
+    }
+}
+
+pub fn handle_barrier_power_ups(
+    mut commands: Commands,
+    mut ev_power_up: EventReader<PowerUpCollected>,
+    mut query: Query<&mut Barrier>,
+    level: Res<Level>,
+) {
+    for power_up in ev_power_up.read() {
+        if power_up.kind != PowerUpKind::Barrier {
+            continue;
+        }
+        let barrier = Barrier::new(&level.barrier);
+        if let Ok(mut existing) = query.get_single_mut() {
+            *existing = barrier;
+        } else {
+            BarrierFactory { barrier }.spawn(&mut commands);
+        }
+    }
+}
+
+pub fn handle_barrier_wall_hit_events(
+    mut ev_wall_hit: EventReader<WallHit>,
+    mut barrier_query: Query<&mut Barrier>,
+) {
+    for wall_hit in ev_wall_hit.read() {
+        if let Ok(mut barrier) = barrier_query.get_mut(wall_hit.wall) {
+            barrier.remaining_saves = barrier.remaining_saves.saturating_sub(1);
+        }
+    }
+}
+
+pub fn update_barrier(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut Barrier, &mut Visibility)>,
+    level: Res<Level>,
+    time: GameTime,
+) {
+    for (entity, mut barrier, mut visibility) in &mut query {
+        barrier.remaining_seconds -= time.delta_seconds();
+        if barrier.is_expired() {
+            commands.entity(entity).despawn_recursive();
+            continue;
+        }
+
+        *visibility = if barrier.is_visible(&level.barrier) {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(10.0, 3, false, true)]
+    #[case(0.0, 3, true, true)]
+    #[case(10.0, 0, true, true)]
+    #[case(2.85, 3, false, true)]
+    #[case(2.75, 3, false, false)]
+    #[case(10.0, 1, false, true)]
+    #[case(9.95, 1, false, false)]
+    fn test_barrier_state(
+        #[case] remaining_seconds: f32,
+        #[case] remaining_saves: u32,
+        #[case] expected_expired: bool,
+        #[case] expected_visible: bool,
+    ) {
+        let barrier = Barrier {
+            remaining_seconds,
+            remaining_saves,
+        };
+        let policy = BarrierPolicy {
+            warning_seconds: 3.0,
+            flicker_hz: 5.0,
+            ..default()
+        };
+
+        assert_eq!(barrier.is_expired(), expected_expired);
+        if !expected_expired {
+            assert_eq!(barrier.is_visible(&policy), expected_visible);
+        }
+    }
+}
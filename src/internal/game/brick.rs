@@ -1,11 +1,51 @@
 use bevy::prelude::*;
 
 use crate::internal::{
+    effects::effects::EffectEvent,
     game::destroy_on_collision::DestroyOnCollision,
     physics::{CollisionBody, CollisionGroup, CollisionMask, CollisionProperties},
 };
 
-use super::score::ScoreOnCollision;
+pub const BRICK_BREAK_PARTICLES: usize = 12;
+pub const BRICK_FLASH_COLOR: Color = Color::WHITE;
+
+use super::{explosive::Explosive, gameplay_events::BrickDestroyed, score::ScoreOnCollision};
+
+#[derive(Bundle)]
+pub struct BrickBundle {
+    sprite_bundle: SpriteBundle,
+    collision_properties: CollisionProperties,
+    destroy_on_collision: DestroyOnCollision,
+    score_on_collision: ScoreOnCollision,
+}
+
+impl BrickBundle {
+    pub fn new(position: Vec2, size: Vec2, color: Color) -> Self {
+        Self {
+            sprite_bundle: SpriteBundle {
+                sprite: Sprite { color, ..default() },
+                transform: Transform {
+                    translation: position.extend(0.0),
+                    scale: size.extend(1.0),
+                    ..default()
+                },
+                ..default()
+            },
+            collision_properties: CollisionProperties {
+                body: CollisionBody::Rect,
+                group: CollisionMask::new(&[CollisionGroup::Block]),
+                mask: CollisionMask::new(&[CollisionGroup::Ball, CollisionGroup::Laser]),
+                ..default()
+            },
+            destroy_on_collision: DestroyOnCollision::This,
+            score_on_collision: ScoreOnCollision(1),
+        }
+    }
+}
+
+/// Grid coordinates (column, row) of a brick spawned by `BrickBlockFactory`
+#[derive(Component, Debug, Clone, Copy)]
+pub struct BrickCoordinates(pub UVec2);
 
 /**
  * Brick Factory
@@ -18,6 +58,10 @@ pub struct BrickBlockFactory {
     pub block_top_margin: f32,
     pub block_bottom_margin: f32,
     pub brick_color: Color,
+    /// Grid coordinates (column, row) of the bricks that explode when destroyed
+    pub explosive_bricks: Vec<UVec2>,
+    pub explosive: Explosive,
+    pub explosive_color: Color,
 }
 impl BrickBlockFactory {
     pub fn spawn(&self, parent: &mut ChildBuilder) {
@@ -63,29 +107,38 @@ impl BrickBlockFactory {
                 );
 
                 // brick
-                parent.spawn((
-                    SpriteBundle {
-                        sprite: Sprite {
-                            color: self.brick_color,
-                            ..default()
-                        },
-                        transform: Transform {
-                            translation: brick_position.extend(0.0),
-                            scale: Vec3::new(self.brick_size.x, self.brick_size.y, 1.0),
-                            ..default()
-                        },
-                        ..default()
-                    },
-                    CollisionProperties {
-                        body: CollisionBody::Rect,
-                        group: CollisionMask::new(&[CollisionGroup::Block]),
-                        mask: CollisionMask::new(&[CollisionGroup::Ball]),
-                        ..default()
-                    },
-                    DestroyOnCollision::This,
-                    ScoreOnCollision(1),
-                ));
+                let coordinates = UVec2::new(column as u32, row as u32);
+                if self.explosive_bricks.contains(&coordinates) {
+                    parent.spawn((
+                        BrickBundle::new(brick_position, self.brick_size, self.explosive_color),
+                        BrickCoordinates(coordinates),
+                        self.explosive,
+                    ));
+                } else {
+                    parent.spawn((
+                        BrickBundle::new(brick_position, self.brick_size, self.brick_color),
+                        BrickCoordinates(coordinates),
+                    ));
+                }
             }
         }
     }
 }
+
+pub fn handle_brick_break_events(
+    mut ev_brick_destroyed: EventReader<BrickDestroyed>,
+    mut ev_effect: EventWriter<EffectEvent>,
+) {
+    for brick in ev_brick_destroyed.read() {
+        ev_effect.send(EffectEvent::Particles {
+            position: brick.position,
+            color: brick.color,
+            count: BRICK_BREAK_PARTICLES,
+        });
+        ev_effect.send(EffectEvent::Flash {
+            position: brick.position,
+            size: brick.size,
+            color: BRICK_FLASH_COLOR,
+        });
+    }
+}
@@ -0,0 +1,310 @@
+use bevy::{prelude::*, utils::HashSet};
+
+use crate::internal::physics::{
+    AngularVelocity, GameTime, LocalTimeScale, RigidBody, TransformInterpolation, UnscaledTime,
+    Velocity,
+};
+
+use super::{
+    ball::Ball,
+    ball_speed::BallSpeed,
+    gameplay_events::{PaddleHit, PowerUpCollected},
+    level::Level,
+    paddle::Paddle,
+    power_up::PowerUpKind,
+};
+
+// Keeps caught balls just above the paddle, so they don't keep colliding with it
+const CATCH_GAP: f32 = 1.0;
+
+#[derive(Debug, Clone, Copy)]
+pub struct CatchPolicy {
+    /// Seconds the paddle keeps catching balls, refreshed by every collected catch power-up
+    pub duration_seconds: f32,
+    /// Seconds a caught ball is held before it is released automatically
+    pub release_timeout_seconds: f32,
+    /// Release angle (in radians, from vertical) of a ball held at the paddle's edge
+    pub max_release_angle: f32,
+    pub release_key: KeyCode,
+}
+
+impl Default for CatchPolicy {
+    fn default() -> Self {
+        Self {
+            duration_seconds: 15.0,
+            release_timeout_seconds: 3.0,
+            max_release_angle: 60f32.to_radians(),
+            release_key: KeyCode::Space,
+        }
+    }
+}
+
+/// Seconds the paddle keeps catching balls
+#[derive(Component, Debug, Default)]
+pub struct CatchPaddle {
+    pub remaining_seconds: f32,
+    /// Set when the release key is pressed, consumed by the next physics tick
+    pub release_requested: bool,
+}
+
+/**
+ * A ball held by the paddle.
+ * Caught balls are kinematic and move with the paddle's velocity, so physics carries them along.
+ */
+#[derive(Component, Debug)]
+pub struct CaughtBall {
+    /// Horizontal offset from the paddle center
+    pub offset: f32,
+    pub remaining_seconds: f32,
+}
+
+/// Launch velocity of a released ball, angled away from the paddle center by its offset
+pub fn release_velocity(offset: f32, paddle_width: f32, max_angle: f32, speed: f32) -> Vec2 {
+    let ratio = (offset / (paddle_width / 2.0)).clamp(-1.0, 1.0);
+    let angle = ratio * max_angle;
+    Vec2::new(angle.sin(), angle.cos()) * speed
+}
+
+pub fn handle_catch_power_ups(
+    mut ev_power_up: EventReader<PowerUpCollected>,
+    mut query: Query<&mut CatchPaddle, With<Paddle>>,
+    level: Res<Level>,
+) {
+    for power_up in ev_power_up.read() {
+        if power_up.kind != PowerUpKind::Catch {
+            continue;
+        }
+        for mut catch_paddle in &mut query {
+            catch_paddle.remaining_seconds = level.catch.duration_seconds;
+        }
+    }
+}
+
+pub fn handle_catch_paddle_hit_events(
+    mut commands: Commands,
+    mut ev_paddle_hit: EventReader<PaddleHit>,
+    paddle_query: Query<(&Transform, &CatchPaddle), (With<Paddle>, Without<Ball>)>,
+    mut ball_query: Query<
+        (
+            &mut Transform,
+            &mut RigidBody,
+            &mut Velocity,
+            &mut TransformInterpolation,
+            Option<&mut AngularVelocity>,
+        ),
+        (With<Ball>, Without<CaughtBall>),
+    >,
+    level: Res<Level>,
+) {
+    let mut caught = HashSet::<Entity>::new();
+    for &PaddleHit { ball, paddle, .. } in ev_paddle_hit.read() {
+        let Ok((paddle_transform, catch_paddle)) = paddle_query.get(paddle) else {
+            continue;
+        };
+        if catch_paddle.remaining_seconds <= 0.0 || caught.contains(&ball) {
+            continue;
+        }
+        let Ok((mut transform, mut rigid_body, mut velocity, mut interpolation, angular_velocity)) =
+            ball_query.get_mut(ball)
+        else {
+            continue;
+        };
+
+        let half_width = paddle_transform.scale.x / 2.0;
+        let offset = (transform.translation.x - paddle_transform.translation.x)
+            .clamp(-half_width, half_width);
+        transform.translation.x = paddle_transform.translation.x + offset;
+        transform.translation.y = paddle_transform.translation.y
+            + (paddle_transform.scale.y + transform.scale.y) / 2.0
+            + CATCH_GAP;
+        interpolation.reset();
+        *rigid_body = RigidBody::Kinematic;
+        velocity.0 = Vec2::ZERO;
+        if let Some(mut angular_velocity) = angular_velocity {
+            angular_velocity.0 = 0.0;
+        }
+
+        commands.entity(ball).insert(CaughtBall {
+            offset,
+            remaining_seconds: level.catch.release_timeout_seconds,
+        });
+        caught.insert(ball);
+    }
+}
+
+/// Requests the release of caught balls, fixed ticks could miss a `just_pressed` between them
+pub fn request_catch_release(
+    keyboard_input: Res<Input<KeyCode>>,
+    mut query: Query<&mut CatchPaddle>,
+    level: Res<Level>,
+) {
+    if keyboard_input.just_pressed(level.catch.release_key) {
+        for mut catch_paddle in &mut query {
+            catch_paddle.release_requested = true;
+        }
+    }
+}
+
+/// Moves caught balls along with the paddle and releases them on request or timeout
+pub fn carry_caught_balls(
+    mut commands: Commands,
+    mut paddle_query: Query<
+        (
+            &Transform,
+            &Velocity,
+            Option<&UnscaledTime>,
+            &mut CatchPaddle,
+        ),
+        (With<Paddle>, Without<Ball>),
+    >,
+    mut ball_query: Query<
+        (
+            Entity,
+            &mut Transform,
+            &mut RigidBody,
+            &mut Velocity,
+            &mut CaughtBall,
+            Option<&LocalTimeScale>,
+        ),
+        With<Ball>,
+    >,
+    ball_speed: Res<BallSpeed>,
+    level: Res<Level>,
+    time: GameTime,
+) {
+    let policy = &level.catch;
+    let Ok((paddle_transform, paddle_velocity, paddle_unscaled_time, mut catch_paddle)) =
+        paddle_query.get_single_mut()
+    else {
+        return;
+    };
+    catch_paddle.remaining_seconds =
+        (catch_paddle.remaining_seconds - time.delta_seconds()).max(0.0);
+
+    let release = std::mem::take(&mut catch_paddle.release_requested);
+    let paddle_width = paddle_transform.scale.x;
+    let paddle_delta_seconds = time.body_delta_seconds(None, paddle_unscaled_time);
+    for (entity, mut transform, mut rigid_body, mut velocity, mut caught_ball, time_scale) in
+        &mut ball_query
+    {
+        // The paddle may have shrunk since the ball was caught
+        caught_ball.offset = caught_ball
+            .offset
+            .clamp(-paddle_width / 2.0, paddle_width / 2.0);
+        caught_ball.remaining_seconds -= time.delta_seconds();
+
+        if release || caught_ball.remaining_seconds <= 0.0 {
+            *rigid_body = RigidBody::Dynamic;
+            velocity.0 = release_velocity(
+                caught_ball.offset,
+                paddle_width,
+                policy.max_release_angle,
+                ball_speed.0,
+            );
+            commands.entity(entity).remove::<CaughtBall>();
+            continue;
+        }
+
+        transform.translation.x = paddle_transform.translation.x + caught_ball.offset;
+        // A ball on a slower clock than the paddle has to move faster to keep up with it
+        let ball_delta_seconds = time.body_delta_seconds(time_scale, None);
+        if ball_delta_seconds > 0.0 {
+            velocity.0 = paddle_velocity.0 * paddle_delta_seconds / ball_delta_seconds;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rstest::rstest;
+
+    use crate::internal::{
+        game::laser::{fire_lasers, LaserBolt, LaserCannon},
+        physics::TimeScale,
+    };
+
+    #[rstest]
+    #[case(0.0, Vec2::new(0.0, 100.0))]
+    #[case(60.0, Vec2::new(100.0, 0.0))]
+    #[case(-90.0, Vec2::new(-100.0, 0.0))]
+    #[case(30.0, Vec2::new(100.0 * 0.5f32.sqrt(), 100.0 * 0.5f32.sqrt()))]
+    fn test_release_velocity(#[case] offset: f32, #[case] expected: Vec2) {
+        let velocity = release_velocity(offset, 120.0, std::f32::consts::FRAC_PI_2, 100.0);
+
+        assert!(velocity.abs_diff_eq(expected, 1e-3), "{velocity}");
+    }
+
+    fn press(app: &mut App, key: KeyCode) {
+        let mut input = app.world.resource_mut::<Input<KeyCode>>();
+        input.clear();
+        input.press(key);
+    }
+
+    #[test]
+    fn test_release_key_press_releases_without_firing() {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .init_resource::<TimeScale>()
+            .init_resource::<Input<KeyCode>>()
+            .init_resource::<Level>()
+            .insert_resource(BallSpeed(100.0))
+            .add_systems(
+                Update,
+                (request_catch_release, fire_lasers, carry_caught_balls).chain(),
+            );
+        app.world.spawn((
+            Paddle,
+            Transform::from_scale(Vec3::new(120.0, 20.0, 1.0)),
+            Velocity(Vec2::ZERO),
+            CatchPaddle {
+                remaining_seconds: 10.0,
+                ..default()
+            },
+            LaserCannon {
+                remaining_seconds: 10.0,
+                ..default()
+            },
+        ));
+        let ball = app
+            .world
+            .spawn((
+                Ball,
+                Transform::default(),
+                RigidBody::Kinematic,
+                Velocity(Vec2::ZERO),
+                CaughtBall {
+                    offset: 0.0,
+                    remaining_seconds: 10.0,
+                },
+            ))
+            .id();
+        let release_key = app.world.resource::<Level>().catch.release_key;
+        let bolt_count = |app: &mut App| {
+            app.world
+                .query_filtered::<(), With<LaserBolt>>()
+                .iter(&app.world)
+                .count()
+        };
+
+        // Holding the key after the press doesn't release it again or fire
+        press(&mut app, release_key);
+        app.update();
+        press(&mut app, release_key);
+        app.update();
+
+        assert!(app.world.get::<CaughtBall>(ball).is_none());
+        assert_eq!(app.world.get::<RigidBody>(ball), Some(&RigidBody::Dynamic));
+        assert_eq!(bolt_count(&mut app), 0);
+
+        app.world
+            .resource_mut::<Input<KeyCode>>()
+            .release(release_key);
+        app.update();
+        press(&mut app, release_key);
+        app.update();
+
+        assert_eq!(bolt_count(&mut app), 2);
+    }
+}
@@ -1,37 +1,40 @@
-use bevy::{prelude::*, utils::HashSet};
+use bevy::prelude::*;
 
-use crate::internal::physics::CollisionEvent;
+use super::gameplay_events::{
+    BallLost, BrickDestroyed, LaserBoltHit, PowerUpCollected, PowerUpMissed,
+};
 
+/// Whether this entity or whatever it collides with is destroyed, `emit_gameplay_events` tells what was destroyed
 #[derive(Component)]
 pub enum DestroyOnCollision {
     This,
     Other,
 }
 
-pub fn handle_destroy_collision_events(
+pub fn despawn_destroyed_entities(
     mut commands: Commands,
-    mut ev_collision: EventReader<CollisionEvent>,
-    collision_query: Query<(Entity, &DestroyOnCollision)>,
+    mut ev_brick_destroyed: EventReader<BrickDestroyed>,
+    mut ev_ball_lost: EventReader<BallLost>,
+    mut ev_power_up_collected: EventReader<PowerUpCollected>,
+    mut ev_power_up_missed: EventReader<PowerUpMissed>,
+    mut ev_laser_bolt_hit: EventReader<LaserBoltHit>,
 ) {
-    let mut removed = HashSet::<Entity>::new();
-    for collision in ev_collision.read() {
-        for entity in collision.collidees {
-            if removed.contains(&entity) {
-                continue;
-            }
-            let other = collision.collidees.iter().find(|&&e| e != entity).unwrap();
-            if let Ok((_, destroy)) = collision_query.get(entity) {
-                match destroy {
-                    DestroyOnCollision::This => {
-                        commands.entity(entity).despawn();
-                        removed.insert(entity);
-                    },
-                    DestroyOnCollision::Other => {
-                        commands.entity(*other).despawn();
-                        removed.insert(*other);
-                    },
-                }
-            }
-        }
+    let destroyed = ev_brick_destroyed
+        .read()
+        .map(|brick| brick.brick)
+        .chain(ev_ball_lost.read().map(|ball_lost| ball_lost.ball))
+        .chain(
+            ev_power_up_collected
+                .read()
+                .map(|power_up| power_up.power_up),
+        )
+        .chain(ev_power_up_missed.read().map(|power_up| power_up.power_up))
+        .chain(
+            ev_laser_bolt_hit
+                .read()
+                .map(|laser_bolt_hit| laser_bolt_hit.bolt),
+        );
+    for entity in destroyed {
+        commands.entity(entity).despawn_recursive();
     }
 }
@@ -0,0 +1,247 @@
+use bevy::prelude::*;
+
+use crate::internal::physics::{CollisionGroup, CollisionMask, GameTime, SpatialQuery};
+
+use super::{
+    brick::BrickCoordinates,
+    game::GameState,
+    gameplay_events::{
+        BrickDestroyCause, BrickDestroyed, DestroyedBricks, Detonation, GameplayEventWriters,
+    },
+    score::ScoreOnCollision,
+};
+
+const CHAIN_DELAY_SECONDS: f32 = 0.15;
+const SHOCKWAVE_SECONDS: f32 = 0.4;
+const SHOCKWAVE_COLOR: Color = Color::rgb(1.0, 0.6, 0.2);
+
+/// Destroys every brick within `radius` when this brick is destroyed
+#[derive(Component, Debug, Clone, Copy)]
+pub struct Explosive {
+    pub radius: f32,
+}
+
+/**
+ * Pending blast, goes off when its timer finishes.
+ * `chain_length` counts the explosions leading to this one and multiplies the score of the bricks it destroys.
+ */
+#[derive(Component)]
+pub struct Explosion {
+    center: Vec2,
+    radius: f32,
+    chain_length: usize,
+    timer: Timer,
+}
+
+#[derive(Component)]
+pub struct Shockwave {
+    center: Vec2,
+    max_radius: f32,
+    timer: Timer,
+}
+
+impl Explosion {
+    fn new(center: Vec2, explosive: &Explosive, chain_length: usize, delay: f32) -> Self {
+        Self {
+            center,
+            radius: explosive.radius,
+            chain_length,
+            timer: Timer::from_seconds(delay, TimerMode::Once),
+        }
+    }
+
+    pub fn chain_length(&self) -> usize {
+        self.chain_length
+    }
+}
+
+/// Destroyed explosive bricks go off, right away when hit and after a delay when caught in another explosion
+pub fn handle_explosive_brick_destroyed_events(
+    mut commands: Commands,
+    mut ev_brick_destroyed: EventReader<BrickDestroyed>,
+) {
+    for brick in ev_brick_destroyed.read() {
+        let Some(explosive) = brick.explosive else {
+            continue;
+        };
+        let explosion = match brick.cause {
+            BrickDestroyCause::Hit { .. } => Explosion::new(brick.position, &explosive, 1, 0.0),
+            BrickDestroyCause::Explosion { chain_length } => Explosion::new(
+                brick.position,
+                &explosive,
+                chain_length + 1,
+                CHAIN_DELAY_SECONDS,
+            ),
+        };
+        commands.spawn((explosion, GameState));
+    }
+}
+
+pub fn update_explosions(
+    mut commands: Commands,
+    mut writers: GameplayEventWriters,
+    mut destroyed_bricks: ResMut<DestroyedBricks>,
+    mut explosion_query: Query<(Entity, &mut Explosion)>,
+    brick_query: Query<(
+        &Transform,
+        &ScoreOnCollision,
+        Option<&Sprite>,
+        Option<&BrickCoordinates>,
+        Option<&Explosive>,
+    )>,
+    spatial_query: SpatialQuery,
+    time: GameTime,
+) {
+    for (explosion_entity, mut explosion) in &mut explosion_query {
+        if !explosion.timer.tick(time.delta()).finished() {
+            continue;
+        }
+        commands.entity(explosion_entity).despawn();
+        commands.spawn((
+            Shockwave {
+                center: explosion.center,
+                max_radius: explosion.radius,
+                timer: Timer::from_seconds(SHOCKWAVE_SECONDS, TimerMode::Once),
+            },
+            GameState,
+        ));
+        writers.detonation.send(Detonation {
+            position: explosion.center,
+            radius: explosion.radius,
+            chain_length: explosion.chain_length,
+        });
+
+        for brick in spatial_query.circle_overlap(
+            explosion.center,
+            explosion.radius,
+            CollisionMask::new(&[CollisionGroup::Block]),
+        ) {
+            let Ok((transform, score, sprite, coordinates, explosive)) = brick_query.get(brick)
+            else {
+                continue;
+            };
+            if !destroyed_bricks.insert(brick) {
+                continue;
+            }
+            writers.brick_destroyed.send(BrickDestroyed {
+                brick,
+                cause: BrickDestroyCause::Explosion {
+                    chain_length: explosion.chain_length,
+                },
+                position: transform.translation.truncate(),
+                size: transform.scale.truncate(),
+                color: sprite.map_or(Color::WHITE, |sprite| sprite.color),
+                points: score.0,
+                coordinates: coordinates.map(|coordinates| coordinates.0),
+                explosive: explosive.copied(),
+            });
+        }
+    }
+}
+
+pub fn draw_shockwaves(
+    mut commands: Commands,
+    mut gizmos: Gizmos,
+    mut query: Query<(Entity, &mut Shockwave)>,
+    time: Res<Time>,
+) {
+    for (entity, mut shockwave) in &mut query {
+        if shockwave.timer.tick(time.delta()).finished() {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        let progress = shockwave.timer.percent();
+        gizmos.circle_2d(
+            shockwave.center,
+            shockwave.max_radius * progress,
+            SHOCKWAVE_COLOR.with_a(1.0 - progress),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rstest::rstest;
+
+    use crate::internal::{
+        effects::effects::EffectEvent,
+        game::{
+            ball::Ball,
+            brick::BrickBundle,
+            destroy_on_collision::despawn_destroyed_entities,
+            gameplay_events::{emit_gameplay_events, GameplayEventsPlugin},
+            level::Level,
+            score::{handle_score_brick_destroyed_events, Scoreboard},
+        },
+        physics::{
+            CollisionBody, CollisionEvent, CollisionMask, CollisionProperties, CollisionResult,
+            TimeScale,
+        },
+    };
+
+    #[rstest]
+    #[case(false)]
+    #[case(true)]
+    fn test_brick_hit_and_caught_in_explosion_scores_once(#[case] hit_this_tick: bool) {
+        let mut app = App::new();
+        app.add_plugins(GameplayEventsPlugin)
+            .init_resource::<Time>()
+            .init_resource::<TimeScale>()
+            .init_resource::<Scoreboard>()
+            .init_resource::<Level>()
+            .add_event::<CollisionEvent>()
+            .add_event::<EffectEvent>()
+            .add_systems(
+                Update,
+                (
+                    emit_gameplay_events,
+                    update_explosions,
+                    (
+                        handle_score_brick_destroyed_events,
+                        despawn_destroyed_entities,
+                    ),
+                )
+                    .chain(),
+            );
+        let brick = app
+            .world
+            .spawn(BrickBundle::new(Vec2::ZERO, Vec2::splat(10.0), Color::RED))
+            .id();
+        app.world.spawn(Explosion::new(
+            Vec2::ZERO,
+            &Explosive { radius: 20.0 },
+            1,
+            0.0,
+        ));
+        if hit_this_tick {
+            let ball = app
+                .world
+                .spawn((
+                    Ball,
+                    Transform::default(),
+                    CollisionProperties {
+                        body: CollisionBody::Circle,
+                        group: CollisionMask::new(&[CollisionGroup::Ball]),
+                        ..default()
+                    },
+                ))
+                .id();
+            app.world.send_event(CollisionEvent {
+                collidees: [brick, ball],
+                collision_result: CollisionResult {
+                    collision_normal: Vec2::Y,
+                    corrigation_vector: Vec2::ZERO,
+                },
+                pass_through: false,
+            });
+        }
+
+        app.update();
+
+        assert_eq!(app.world.resource::<Events<BrickDestroyed>>().len(), 1);
+        assert_eq!(app.world.resource::<Scoreboard>().score(), 1);
+        assert!(app.world.get_entity(brick).is_none());
+    }
+}
@@ -0,0 +1,78 @@
+use bevy::prelude::*;
+
+use crate::internal::physics::{CollisionGroup, CollisionMask, GameTime, PassThrough};
+
+use super::{
+    ball::{Ball, BALL_COLOR},
+    gameplay_events::PowerUpCollected,
+    level::Level,
+    power_up::PowerUpKind,
+};
+
+const FIREBALL_COLOR: Color = Color::rgb(1.0, 0.5, 0.0);
+
+#[derive(Debug, Clone, Copy)]
+pub struct FireballPolicy {
+    /// Seconds balls pierce through bricks, refreshed by every collected fireball power-up
+    pub duration_seconds: f32,
+}
+
+impl Default for FireballPolicy {
+    fn default() -> Self {
+        Self {
+            duration_seconds: 8.0,
+        }
+    }
+}
+
+/// Seconds the ball keeps destroying bricks without bouncing off them
+#[derive(Component, Debug)]
+pub struct Fireball {
+    pub remaining_seconds: f32,
+}
+
+pub fn handle_fireball_power_ups(
+    mut commands: Commands,
+    mut ev_power_up: EventReader<PowerUpCollected>,
+    ball_query: Query<(Entity, &Handle<ColorMaterial>), With<Ball>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    level: Res<Level>,
+) {
+    for power_up in ev_power_up.read() {
+        if power_up.kind != PowerUpKind::Fireball {
+            continue;
+        }
+        for (entity, material) in &ball_query {
+            commands.entity(entity).insert((
+                Fireball {
+                    remaining_seconds: level.fireball.duration_seconds,
+                },
+                PassThrough {
+                    mask: CollisionMask::new(&[CollisionGroup::Block]),
+                },
+            ));
+            if let Some(material) = materials.get_mut(material) {
+                material.color = FIREBALL_COLOR;
+            }
+        }
+    }
+}
+
+pub fn update_fireballs(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut Fireball, &Handle<ColorMaterial>)>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    time: GameTime,
+) {
+    for (entity, mut fireball, material) in &mut query {
+        fireball.remaining_seconds -= time.delta_seconds();
+        if fireball.remaining_seconds > 0.0 {
+            continue;
+        }
+
+        commands.entity(entity).remove::<(Fireball, PassThrough)>();
+        if let Some(material) = materials.get_mut(material) {
+            material.color = BALL_COLOR;
+        }
+    }
+}
@@ -1,28 +1,46 @@
-use bevy::{prelude::*, sprite::MaterialMesh2dBundle};
+use bevy::prelude::*;
 
 use crate::internal::{
-    physics::{
-        CollisionBody, CollisionGroup, CollisionMask, CollisionProperties, PhysicsLabel, Velocity,
-    },
+    physics::{PhysicsLabel, SpeedLimit, TimeScale},
     states::AppState,
 };
 
 use super::{
-    brick::BrickBlockFactory,
-    destroy_on_collision::handle_destroy_collision_events,
+    anti_loop::handle_loop_guard,
+    ball::{update_ball_trails, BallFactory, BALL_STARTING_POSITION, INITIAL_BALL_DIRECTION},
+    ball_speed::{handle_ball_speed_events, sync_ball_speed_limits, BallSpeed},
+    barrier::{handle_barrier_power_ups, handle_barrier_wall_hit_events, update_barrier},
+    brick::{handle_brick_break_events, BrickBlockFactory},
+    catch::{
+        carry_caught_balls, handle_catch_paddle_hit_events, handle_catch_power_ups,
+        request_catch_release,
+    },
+    destroy_on_collision::despawn_destroyed_entities,
+    explosive::{
+        draw_shockwaves, handle_explosive_brick_destroyed_events, update_explosions, Explosive,
+    },
+    fireball::{handle_fireball_power_ups, update_fireballs},
+    gameplay_events::{emit_gameplay_events, GameplayEventsPlugin},
+    impact::{handle_explosion_impacts, handle_impact_events},
+    laser::{fire_lasers, handle_laser_power_ups, update_laser_indicator, LaserIndicatorFactory},
+    level::Level,
+    loot::{handle_loot_events, LootRng},
+    moving_brick::{move_bricks, MovingBrickFactory},
     paddle::{move_paddle, PaddleFactory},
-    score::{handle_score_on_collision_events, update_scoreboard, Scoreboard, ScoreboardFactory},
+    paddle_width::{handle_paddle_width_power_ups, update_paddle_width},
+    score::{
+        handle_combo_events, handle_score_brick_destroyed_events, update_level_clock,
+        update_score_multiplier, update_scoreboard, LevelResult, ScoreOnCollision, Scoreboard,
+        ScoreboardFactory,
+    },
+    slow_motion::{handle_slow_ball_power_ups, update_bullet_time, update_slow_balls, BulletTime},
+    sound_effects::{
+        handle_ball_sound_events, handle_brick_sound_events, handle_explosion_sounds,
+        handle_power_up_sound_events,
+    },
     wall::{EdgeWallFactory, BOTTOM_WALL, LEFT_WALL, RIGHT_WALL, TOP_WALL},
 };
 
-// We set the z-value of the ball to 1 so it renders on top in the case of overlapping sprites.
-const BALL_SIZE: Vec3 = Vec3::new(20.0, 20.0, 0.0);
-const BALL_STARTING_POSITION: Vec3 = Vec3::new(0.0, BOTTOM_WALL + 60. + 20. + 20., 1.0);
-const BALL_STARTING_SPEED: f32 = 200.0;
-const INITIAL_BALL_DIRECTION: Vec2 = Vec2::new(0.5, 0.5);
-
-const BALL_COLOR: Color = Color::rgb(1.0, 0.5, 0.5);
-
 pub struct GamePlugin;
 
 #[derive(Component)]
@@ -30,29 +48,83 @@ pub struct GameState;
 
 impl Plugin for GamePlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(OnEnter(AppState::Game), game_setup)
+        app.add_plugins(GameplayEventsPlugin)
+            .init_resource::<Level>()
+            .add_systems(OnEnter(AppState::Game), game_setup)
             .add_systems(OnExit(AppState::Game), game_teardown)
             .add_systems(
                 FixedUpdate,
                 (
-                    move_paddle.before(PhysicsLabel),
                     (
-                        handle_destroy_collision_events,
-                        handle_score_on_collision_events,
+                        (
+                            handle_paddle_width_power_ups,
+                            update_paddle_width,
+                            move_paddle,
+                            handle_catch_power_ups,
+                            carry_caught_balls,
+                        )
+                            .chain(),
+                        (handle_laser_power_ups, fire_lasers)
+                            .chain()
+                            .before(carry_caught_balls),
+                        (handle_fireball_power_ups, update_fireballs).chain(),
+                        (handle_barrier_power_ups, update_barrier).chain(),
+                        (handle_slow_ball_power_ups, update_slow_balls).chain(),
+                        update_bullet_time,
+                        update_score_multiplier,
+                        move_bricks,
+                        sync_ball_speed_limits,
                     )
+                        .before(PhysicsLabel),
+                    (emit_gameplay_events, update_explosions)
+                        .chain()
                         .after(PhysicsLabel),
+                    (
+                        handle_score_brick_destroyed_events,
+                        handle_combo_events,
+                        handle_ball_speed_events,
+                        handle_loop_guard,
+                        handle_explosive_brick_destroyed_events,
+                        handle_loot_events,
+                        despawn_destroyed_entities,
+                        handle_catch_paddle_hit_events,
+                        handle_barrier_wall_hit_events,
+                        handle_brick_break_events,
+                        handle_impact_events,
+                        handle_explosion_impacts,
+                        handle_ball_sound_events,
+                        handle_brick_sound_events,
+                        handle_power_up_sound_events,
+                        handle_explosion_sounds,
+                    )
+                        .after(emit_gameplay_events)
+                        .after(update_explosions),
+                    check_level_complete.after(despawn_destroyed_entities),
                 )
                     .run_if(in_state(AppState::Game)),
             )
             .add_systems(
                 Update,
-                (update_scoreboard, esc_to_menu).run_if(in_state(AppState::Game)),
+                (
+                    update_scoreboard,
+                    update_level_clock,
+                    update_laser_indicator,
+                    request_catch_release,
+                    update_ball_trails,
+                    draw_shockwaves,
+                    esc_to_menu,
+                )
+                    .run_if(in_state(AppState::Game)),
             );
     }
 }
 
 fn game_teardown(mut commands: Commands, query: Query<Entity, With<GameState>>) {
     commands.remove_resource::<Scoreboard>();
+    commands.remove_resource::<BallSpeed>();
+    commands.remove_resource::<LootRng>();
+    commands.remove_resource::<BulletTime>();
+    commands.insert_resource(TimeScale::default());
     for entity in query.iter() {
         // despawn the entity and its children
         commands.entity(entity).despawn_recursive();
@@ -63,57 +135,36 @@ fn game_setup(
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<ColorMaterial>>,
+    level: Res<Level>,
 ) {
-    commands.insert_resource(Scoreboard { score: 0 });
+    let ball_speed = level.ball_speed;
+    commands.insert_resource(Scoreboard::default());
+    commands.insert_resource(BallSpeed(ball_speed.starting_speed));
+    commands.insert_resource(LootRng::new(level.loot.seed));
+    commands.insert_resource(BulletTime::default());
 
     commands
         .spawn((SpatialBundle::default(), GameState))
         .with_children(|parent| {
-            PaddleFactory.spawn(parent);
-
-            // Ball
-            parent.spawn((
-                MaterialMesh2dBundle {
-                    mesh: meshes.add(shape::Circle::default().into()).into(),
-                    material: materials.add(ColorMaterial::from(BALL_COLOR)),
-                    transform: Transform::from_translation(BALL_STARTING_POSITION)
-                        .with_scale(BALL_SIZE),
-                    ..default()
-                },
-                Velocity(INITIAL_BALL_DIRECTION.normalize() * BALL_STARTING_SPEED),
-                CollisionProperties {
-                    body: CollisionBody::Circle,
-                    group: CollisionMask::new(&[CollisionGroup::Ball]),
-                    mask: CollisionMask::new(&[
-                        CollisionGroup::Block,
-                        CollisionGroup::Wall,
-                        CollisionGroup::Paddle,
-                    ]),
-                    ..default()
-                },
-            ));
-            parent.spawn((
-                MaterialMesh2dBundle {
-                    mesh: meshes.add(shape::Circle::default().into()).into(),
-                    material: materials.add(ColorMaterial::from(BALL_COLOR)),
-                    transform: Transform::from_translation(BALL_STARTING_POSITION)
-                        .with_scale(BALL_SIZE),
-                    ..default()
-                },
-                Velocity(INITIAL_BALL_DIRECTION.normalize() * BALL_STARTING_SPEED),
-                CollisionProperties {
-                    body: CollisionBody::Circle,
-                    group: CollisionMask::new(&[CollisionGroup::Ball]),
-                    mask: CollisionMask::new(&[
-                        CollisionGroup::Block,
-                        CollisionGroup::Wall,
-                        CollisionGroup::Paddle,
-                    ]),
-                    ..default()
-                },
-            ));
+            PaddleFactory {
+                width: level.paddle_width.base_width,
+            }
+            .spawn(parent);
+
+            for _ in 0..2 {
+                BallFactory {
+                    position: BALL_STARTING_POSITION,
+                    velocity: INITIAL_BALL_DIRECTION.normalize() * ball_speed.starting_speed,
+                    speed_limit: SpeedLimit {
+                        min: ball_speed.min_speed,
+                        max: ball_speed.max_speed,
+                    },
+                }
+                .spawn(parent, &mut meshes, &mut materials);
+            }
 
             ScoreboardFactory.spawn(parent);
+            LaserIndicatorFactory.spawn(parent);
 
             EdgeWallFactory.spawn(parent);
 
@@ -128,11 +179,38 @@ fn game_setup(
                 brick_size: Vec2::new(60., 30.),
                 brick_margin: 5.,
                 brick_color: Color::rgb(0.5, 0.5, 1.0),
+                explosive_bricks: level.explosive_bricks.clone(),
+                explosive: Explosive {
+                    radius: level.explosion_radius,
+                },
+                explosive_color: Color::rgb(1.0, 0.6, 0.2),
+            }
+            .spawn(parent);
+
+            MovingBrickFactory {
+                specs: &level.moving_bricks,
             }
             .spawn(parent);
         });
 }
 
+fn check_level_complete(
+    mut commands: Commands,
+    mut next_state: ResMut<NextState<AppState>>,
+    scoreboard: Res<Scoreboard>,
+    level: Res<Level>,
+    brick_query: Query<(), With<ScoreOnCollision>>,
+) {
+    if !brick_query.is_empty() {
+        return;
+    }
+
+    let mut result = scoreboard.clone();
+    result.finish(&level.score);
+    commands.insert_resource(LevelResult(result));
+    next_state.set(AppState::LevelComplete);
+}
+
 pub fn esc_to_menu(mut next_state: ResMut<NextState<AppState>>, input: Res<Input<KeyCode>>) {
     if input.just_pressed(KeyCode::Escape) {
         next_state.set(AppState::MainMenu);
@@ -0,0 +1,415 @@
+use bevy::{
+    ecs::{query::WorldQuery, system::SystemParam},
+    prelude::*,
+    utils::HashSet,
+};
+
+use crate::internal::physics::{CollisionEvent, CollisionGroup, CollisionProperties, Velocity};
+
+use super::{
+    ball::Ball,
+    brick::BrickCoordinates,
+    destroy_on_collision::DestroyOnCollision,
+    explosive::Explosive,
+    paddle::Paddle,
+    power_up::{PowerUp, PowerUpKind},
+    score::ScoreOnCollision,
+};
+
+/// A ball or laser bolt hit a brick, whether or not the hit destroyed it
+#[derive(Event, Debug, Clone, Copy)]
+pub struct BrickHit {
+    pub brick: Entity,
+    pub by: Entity,
+    pub position: Vec2,
+    pub velocity: Vec2,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BrickDestroyCause {
+    /// Broken by a ball or laser bolt moving at `velocity`
+    Hit { by: Entity, velocity: Vec2 },
+    /// Caught in an explosion, `chain_length` explosions deep
+    Explosion { chain_length: usize },
+}
+
+#[derive(Event, Debug, Clone, Copy)]
+pub struct BrickDestroyed {
+    pub brick: Entity,
+    pub cause: BrickDestroyCause,
+    pub position: Vec2,
+    pub size: Vec2,
+    pub color: Color,
+    pub points: usize,
+    pub coordinates: Option<UVec2>,
+    pub explosive: Option<Explosive>,
+}
+
+/// A ball bounced off the paddle, `velocity` is the ball's velocity after the bounce
+#[derive(Event, Debug, Clone, Copy)]
+pub struct PaddleHit {
+    pub ball: Entity,
+    pub paddle: Entity,
+    pub velocity: Vec2,
+}
+
+/// A ball bounced off a wall or the barrier
+#[derive(Event, Debug, Clone, Copy)]
+pub struct WallHit {
+    pub ball: Entity,
+    pub wall: Entity,
+    pub velocity: Vec2,
+}
+
+#[derive(Event, Debug, Clone, Copy)]
+pub struct BallLost {
+    pub ball: Entity,
+    pub position: Vec2,
+}
+
+#[derive(Event, Debug, Clone, Copy)]
+pub struct PowerUpCollected {
+    pub power_up: Entity,
+    pub kind: PowerUpKind,
+}
+
+/// A power-up fell past the paddle
+#[derive(Event, Debug, Clone, Copy)]
+pub struct PowerUpMissed {
+    pub power_up: Entity,
+}
+
+/// A laser bolt hit a brick or a wall
+#[derive(Event, Debug, Clone, Copy)]
+pub struct LaserBoltHit {
+    pub bolt: Entity,
+}
+
+/// An explosive brick went off, `chain_length` explosions deep
+#[derive(Event, Debug, Clone, Copy)]
+pub struct Detonation {
+    pub position: Vec2,
+    pub radius: f32,
+    pub chain_length: usize,
+}
+
+pub struct GameplayEventsPlugin;
+
+impl Plugin for GameplayEventsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DestroyedBricks>()
+            .add_event::<BrickHit>()
+            .add_event::<BrickDestroyed>()
+            .add_event::<PaddleHit>()
+            .add_event::<WallHit>()
+            .add_event::<BallLost>()
+            .add_event::<PowerUpCollected>()
+            .add_event::<PowerUpMissed>()
+            .add_event::<LaserBoltHit>()
+            .add_event::<Detonation>();
+    }
+}
+
+/// Bricks a `BrickDestroyed` was sent for this tick, so bricks hit and caught in an explosion are destroyed once
+#[derive(Resource, Default)]
+pub struct DestroyedBricks(HashSet<Entity>);
+
+impl DestroyedBricks {
+    /// Returns false if the brick was already destroyed this tick
+    pub fn insert(&mut self, brick: Entity) -> bool {
+        self.0.insert(brick)
+    }
+}
+
+#[derive(SystemParam)]
+pub struct GameplayEventWriters<'w> {
+    pub brick_hit: EventWriter<'w, BrickHit>,
+    pub brick_destroyed: EventWriter<'w, BrickDestroyed>,
+    pub paddle_hit: EventWriter<'w, PaddleHit>,
+    pub wall_hit: EventWriter<'w, WallHit>,
+    pub ball_lost: EventWriter<'w, BallLost>,
+    pub power_up_collected: EventWriter<'w, PowerUpCollected>,
+    pub power_up_missed: EventWriter<'w, PowerUpMissed>,
+    pub laser_bolt_hit: EventWriter<'w, LaserBoltHit>,
+    pub detonation: EventWriter<'w, Detonation>,
+}
+
+#[derive(WorldQuery)]
+pub struct CollideeQuery {
+    entity: Entity,
+    transform: &'static Transform,
+    collision_properties: &'static CollisionProperties,
+    velocity: Option<&'static Velocity>,
+    destroy: Option<&'static DestroyOnCollision>,
+    score: Option<&'static ScoreOnCollision>,
+    sprite: Option<&'static Sprite>,
+    coordinates: Option<&'static BrickCoordinates>,
+    explosive: Option<&'static Explosive>,
+    power_up: Option<&'static PowerUp>,
+    ball: Has<Ball>,
+    paddle: Has<Paddle>,
+}
+
+impl CollideeQueryItem<'_> {
+    fn position(&self) -> Vec2 {
+        self.transform.translation.truncate()
+    }
+
+    fn velocity(&self) -> Vec2 {
+        self.velocity.map_or(Vec2::ZERO, |velocity| velocity.0)
+    }
+
+    fn in_group(&self, group: CollisionGroup) -> bool {
+        self.collision_properties.group.contains_group(group)
+    }
+}
+
+/**
+ * Translates the physics `CollisionEvent`s into gameplay events, once per collision.
+ * Events keep what their consumers need, as the entities involved may be despawned by the time they are read.
+ */
+pub fn emit_gameplay_events(
+    mut ev_collision: EventReader<CollisionEvent>,
+    mut writers: GameplayEventWriters,
+    mut destroyed_bricks: ResMut<DestroyedBricks>,
+    query: Query<CollideeQuery>,
+) {
+    destroyed_bricks.0.clear();
+    // Substeps may report the same pair several times per tick
+    let mut handled_pairs = HashSet::<[Entity; 2]>::new();
+    let mut handled = HashSet::<Entity>::new();
+    for collision in ev_collision.read() {
+        let Ok(collidees) = query.get_many(collision.collidees) else {
+            continue;
+        };
+        for [this, other] in [
+            [&collidees[0], &collidees[1]],
+            [&collidees[1], &collidees[0]],
+        ] {
+            if !handled_pairs.insert([this.entity, other.entity]) {
+                continue;
+            }
+
+            if this.ball {
+                if let Some(DestroyOnCollision::Other) = other.destroy {
+                    if handled.insert(this.entity) {
+                        writers.ball_lost.send(BallLost {
+                            ball: this.entity,
+                            position: this.position(),
+                        });
+                    }
+                } else if other.paddle {
+                    writers.paddle_hit.send(PaddleHit {
+                        ball: this.entity,
+                        paddle: other.entity,
+                        velocity: this.velocity(),
+                    });
+                } else if other.in_group(CollisionGroup::Wall) {
+                    writers.wall_hit.send(WallHit {
+                        ball: this.entity,
+                        wall: other.entity,
+                        velocity: this.velocity(),
+                    });
+                }
+            }
+
+            if this.ball || this.in_group(CollisionGroup::Laser) {
+                if let Some(score) = other.score {
+                    writers.brick_hit.send(BrickHit {
+                        brick: other.entity,
+                        by: this.entity,
+                        position: other.position(),
+                        velocity: this.velocity(),
+                    });
+                    if let Some(DestroyOnCollision::This) = other.destroy {
+                        if destroyed_bricks.insert(other.entity) {
+                            writers.brick_destroyed.send(BrickDestroyed {
+                                brick: other.entity,
+                                cause: BrickDestroyCause::Hit {
+                                    by: this.entity,
+                                    velocity: this.velocity(),
+                                },
+                                position: other.position(),
+                                size: other.transform.scale.truncate(),
+                                color: other.sprite.map_or(Color::WHITE, |sprite| sprite.color),
+                                points: score.0,
+                                coordinates: other.coordinates.map(|coordinates| coordinates.0),
+                                explosive: other.explosive.copied(),
+                            });
+                        }
+                    }
+                }
+            }
+
+            if this.in_group(CollisionGroup::Laser) && handled.insert(this.entity) {
+                writers
+                    .laser_bolt_hit
+                    .send(LaserBoltHit { bolt: this.entity });
+            }
+
+            if let Some(power_up) = this.power_up {
+                if other.paddle && handled.insert(this.entity) {
+                    writers.power_up_collected.send(PowerUpCollected {
+                        power_up: this.entity,
+                        kind: power_up.0,
+                    });
+                } else if let Some(DestroyOnCollision::Other) = other.destroy {
+                    if handled.insert(this.entity) {
+                        writers.power_up_missed.send(PowerUpMissed {
+                            power_up: this.entity,
+                        });
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::internal::{
+        game::brick::BrickBundle,
+        physics::{CollisionBody, CollisionMask, CollisionResult},
+    };
+
+    fn body(group: CollisionGroup) -> CollisionProperties {
+        CollisionProperties {
+            body: CollisionBody::Rect,
+            group: CollisionMask::new(&[group]),
+            ..default()
+        }
+    }
+
+    fn collide(app: &mut App, collidees: [Entity; 2]) {
+        app.world.send_event(CollisionEvent {
+            collidees,
+            collision_result: CollisionResult {
+                collision_normal: Vec2::Y,
+                corrigation_vector: Vec2::ZERO,
+            },
+            pass_through: false,
+        });
+    }
+
+    fn read<E: Event + Copy>(app: &App) -> Vec<E> {
+        let events = app.world.resource::<Events<E>>();
+        events.get_reader().read(events).copied().collect()
+    }
+
+    #[test]
+    fn test_emit_gameplay_events() {
+        let mut app = App::new();
+        app.add_plugins(GameplayEventsPlugin)
+            .add_event::<CollisionEvent>()
+            .add_systems(Update, emit_gameplay_events);
+
+        let ball = app
+            .world
+            .spawn((
+                Ball,
+                Transform::default(),
+                body(CollisionGroup::Ball),
+                Velocity(Vec2::new(0.0, 300.0)),
+            ))
+            .id();
+        let brick = app
+            .world
+            .spawn((
+                BrickBundle::new(Vec2::new(10.0, 20.0), Vec2::ONE, Color::RED),
+                BrickCoordinates(UVec2::new(1, 2)),
+            ))
+            .id();
+        let paddle = app
+            .world
+            .spawn((Paddle, Transform::default(), body(CollisionGroup::Paddle)))
+            .id();
+        let wall = app
+            .world
+            .spawn((Transform::default(), body(CollisionGroup::Wall)))
+            .id();
+        let floor = app
+            .world
+            .spawn((
+                Transform::default(),
+                body(CollisionGroup::Wall),
+                DestroyOnCollision::Other,
+            ))
+            .id();
+        let power_up = app
+            .world
+            .spawn((
+                PowerUp(PowerUpKind::Laser),
+                Transform::default(),
+                body(CollisionGroup::Powerup),
+            ))
+            .id();
+
+        let missed_power_up = app
+            .world
+            .spawn((
+                PowerUp(PowerUpKind::Catch),
+                Transform::default(),
+                body(CollisionGroup::Powerup),
+            ))
+            .id();
+        let bolt = app
+            .world
+            .spawn((Transform::default(), body(CollisionGroup::Laser)))
+            .id();
+
+        // Substeps report the same pairs again
+        for _ in 0..2 {
+            collide(&mut app, [missed_power_up, floor]);
+            collide(&mut app, [wall, bolt]);
+            collide(&mut app, [ball, brick]);
+            collide(&mut app, [paddle, ball]);
+            collide(&mut app, [ball, wall]);
+            collide(&mut app, [floor, ball]);
+            collide(&mut app, [power_up, paddle]);
+        }
+        app.update();
+
+        let destroyed = read::<BrickDestroyed>(&app);
+        assert_eq!(destroyed.len(), 1);
+        assert_eq!(destroyed[0].brick, brick);
+        assert_eq!(
+            destroyed[0].cause,
+            BrickDestroyCause::Hit {
+                by: ball,
+                velocity: Vec2::new(0.0, 300.0)
+            }
+        );
+        assert_eq!(destroyed[0].position, Vec2::new(10.0, 20.0));
+        assert_eq!(destroyed[0].coordinates, Some(UVec2::new(1, 2)));
+        let brick_hits = read::<BrickHit>(&app);
+        assert_eq!(brick_hits.len(), 1);
+        assert_eq!((brick_hits[0].brick, brick_hits[0].by), (brick, ball));
+
+        let paddle_hits = read::<PaddleHit>(&app);
+        assert_eq!(paddle_hits.len(), 1);
+        assert_eq!((paddle_hits[0].ball, paddle_hits[0].paddle), (ball, paddle));
+
+        let wall_hits = read::<WallHit>(&app);
+        assert_eq!(wall_hits.len(), 1);
+        assert_eq!(wall_hits[0].wall, wall);
+
+        let lost = read::<BallLost>(&app);
+        assert_eq!(lost.len(), 1);
+        assert_eq!(lost[0].ball, ball);
+
+        let collected = read::<PowerUpCollected>(&app);
+        assert_eq!(collected.len(), 1);
+        assert_eq!(collected[0].power_up, power_up);
+        assert_eq!(collected[0].kind, PowerUpKind::Laser);
+
+        let missed = read::<PowerUpMissed>(&app);
+        assert_eq!(missed.len(), 1);
+        assert_eq!(missed[0].power_up, missed_power_up);
+
+        let bolt_hits = read::<LaserBoltHit>(&app);
+        assert_eq!(bolt_hits.len(), 1);
+        assert_eq!(bolt_hits[0].bolt, bolt);
+    }
+}
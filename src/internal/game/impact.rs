@@ -0,0 +1,46 @@
+use bevy::prelude::*;
+
+use crate::internal::camera::CameraImpact;
+
+use super::gameplay_events::{BallLost, Detonation, PaddleHit};
+
+const LOST_BALL_TRAUMA: f32 = 0.6;
+const EXPLOSION_TRAUMA: f32 = 0.35;
+const EXPLOSION_HIT_STOP_SECONDS: f32 = 0.06;
+// Paddle hits sending the ball off faster than this shake the camera
+const FAST_PADDLE_HIT_SPEED: f32 = 450.0;
+const FAST_PADDLE_HIT_TRAUMA: f32 = 0.2;
+const FAST_PADDLE_HIT_STOP_SECONDS: f32 = 0.04;
+
+pub fn handle_impact_events(
+    mut ev_paddle_hit: EventReader<PaddleHit>,
+    mut ev_ball_lost: EventReader<BallLost>,
+    mut ev_impact: EventWriter<CameraImpact>,
+) {
+    for paddle_hit in ev_paddle_hit.read() {
+        if paddle_hit.velocity.length() >= FAST_PADDLE_HIT_SPEED {
+            ev_impact.send(CameraImpact {
+                trauma: FAST_PADDLE_HIT_TRAUMA,
+                hit_stop_seconds: FAST_PADDLE_HIT_STOP_SECONDS,
+            });
+        }
+    }
+    for _ in ev_ball_lost.read() {
+        ev_impact.send(CameraImpact {
+            trauma: LOST_BALL_TRAUMA,
+            ..default()
+        });
+    }
+}
+
+pub fn handle_explosion_impacts(
+    mut ev_detonation: EventReader<Detonation>,
+    mut ev_impact: EventWriter<CameraImpact>,
+) {
+    for _ in ev_detonation.read() {
+        ev_impact.send(CameraImpact {
+            trauma: EXPLOSION_TRAUMA,
+            hit_stop_seconds: EXPLOSION_HIT_STOP_SECONDS,
+        });
+    }
+}
@@ -0,0 +1,239 @@
+use bevy::prelude::*;
+
+use crate::internal::physics::{
+    CollisionBody, CollisionGroup, CollisionMask, CollisionProperties, GameTime, RigidBody,
+    TransformInterpolation, Velocity,
+};
+
+use super::{
+    catch::CaughtBall, destroy_on_collision::DestroyOnCollision, game::GameState,
+    gameplay_events::PowerUpCollected, level::Level, paddle::Paddle, power_up::PowerUpKind,
+};
+
+const LASER_BOLT_SIZE: Vec2 = Vec2::new(4.0, 16.0);
+const LASER_BOLT_COLOR: Color = Color::rgb(1.0, 0.2, 0.4);
+// Distance of the bolts from the paddle's edges
+const LASER_BOLT_INSET: f32 = 10.0;
+
+const LASER_INDICATOR_FONT_SIZE: f32 = 30.0;
+const LASER_INDICATOR_PADDING: Val = Val::Px(5.0);
+const LASER_INDICATOR_COLOR: Color = Color::rgb(1.0, 0.2, 0.4);
+
+#[derive(Debug, Clone, Copy)]
+pub struct LaserPolicy {
+    /// Seconds the laser stays active, refreshed by every collected laser power-up
+    pub duration_seconds: f32,
+    /// Minimum seconds between two pairs of bolts
+    pub fire_interval_seconds: f32,
+    pub bolt_speed: f32,
+    pub fire_key: KeyCode,
+}
+
+impl Default for LaserPolicy {
+    fn default() -> Self {
+        Self {
+            duration_seconds: 10.0,
+            fire_interval_seconds: 0.35,
+            bolt_speed: 700.0,
+            fire_key: KeyCode::Space,
+        }
+    }
+}
+
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq)]
+pub struct LaserCannon {
+    pub remaining_seconds: f32,
+    pub cooldown_seconds: f32,
+}
+
+impl LaserCannon {
+    pub fn is_active(&self) -> bool {
+        self.remaining_seconds > 0.0
+    }
+
+    /// Advances the timers, returns whether a pair of bolts is fired
+    pub fn tick(&mut self, delta_seconds: f32, trigger: bool, policy: &LaserPolicy) -> bool {
+        self.cooldown_seconds = (self.cooldown_seconds - delta_seconds).max(0.0);
+        if !self.is_active() {
+            return false;
+        }
+        self.remaining_seconds = (self.remaining_seconds - delta_seconds).max(0.0);
+
+        if trigger && self.cooldown_seconds == 0.0 {
+            self.cooldown_seconds = policy.fire_interval_seconds;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+#[derive(Component)]
+pub struct LaserBolt;
+
+pub struct LaserBoltFactory {
+    pub position: Vec2,
+    pub speed: f32,
+}
+
+impl LaserBoltFactory {
+    pub fn spawn(&self, commands: &mut Commands) {
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: LASER_BOLT_COLOR,
+                    ..default()
+                },
+                transform: Transform {
+                    translation: self.position.extend(0.5),
+                    scale: LASER_BOLT_SIZE.extend(1.0),
+                    ..default()
+                },
+                ..default()
+            },
+            LaserBolt,
+            GameState,
+            RigidBody::Kinematic,
+            Velocity(Vec2::Y * self.speed),
+            TransformInterpolation::default(),
+            DestroyOnCollision::This,
+            CollisionProperties {
+                body: CollisionBody::Rect,
+                group: CollisionMask::new(&[CollisionGroup::Laser]),
+                mask: CollisionMask::new(&[CollisionGroup::Block, CollisionGroup::Wall]),
+                ..default()
+            },
+        ));
+    }
+}
+
+pub fn handle_laser_power_ups(
+    mut ev_power_up: EventReader<PowerUpCollected>,
+    mut query: Query<&mut LaserCannon, With<Paddle>>,
+    level: Res<Level>,
+) {
+    for power_up in ev_power_up.read() {
+        if power_up.kind != PowerUpKind::Laser {
+            continue;
+        }
+        for mut laser_cannon in &mut query {
+            laser_cannon.remaining_seconds = level.laser.duration_seconds;
+        }
+    }
+}
+
+pub fn fire_lasers(
+    mut commands: Commands,
+    keyboard_input: Res<Input<KeyCode>>,
+    mut query: Query<(&Transform, &mut LaserCannon), With<Paddle>>,
+    caught_query: Query<(), With<CaughtBall>>,
+    mut held_by_catch: Local<bool>,
+    level: Res<Level>,
+    time: GameTime,
+) {
+    let policy = &level.laser;
+    // The catch power-up releases balls with the same key,
+    // a press made while holding a ball doesn't fire until the key is let go
+    let pressed = keyboard_input.pressed(policy.fire_key);
+    if !pressed {
+        *held_by_catch = false;
+    } else if !caught_query.is_empty() {
+        *held_by_catch = true;
+    }
+    let trigger = pressed && !*held_by_catch;
+    for (transform, mut laser_cannon) in &mut query {
+        if !laser_cannon.tick(time.delta_seconds(), trigger, policy) {
+            continue;
+        }
+
+        let paddle_position = transform.translation.truncate();
+        let paddle_size = transform.scale.truncate();
+        for side in [-1.0, 1.0] {
+            LaserBoltFactory {
+                position: paddle_position
+                    + Vec2::new(
+                        side * (paddle_size.x / 2.0 - LASER_BOLT_INSET),
+                        (paddle_size.y + LASER_BOLT_SIZE.y) / 2.0,
+                    ),
+                speed: policy.bolt_speed,
+            }
+            .spawn(&mut commands);
+        }
+    }
+}
+
+#[derive(Component)]
+pub struct LaserIndicator;
+
+pub struct LaserIndicatorFactory;
+
+impl LaserIndicatorFactory {
+    pub fn spawn(&self, parent: &mut ChildBuilder) {
+        parent.spawn((
+            TextBundle::from_section(
+                "",
+                TextStyle {
+                    font_size: LASER_INDICATOR_FONT_SIZE,
+                    color: LASER_INDICATOR_COLOR,
+                    ..default()
+                },
+            )
+            .with_style(Style {
+                position_type: PositionType::Absolute,
+                top: LASER_INDICATOR_PADDING,
+                right: LASER_INDICATOR_PADDING,
+                ..default()
+            }),
+            LaserIndicator,
+        ));
+    }
+}
+
+pub fn update_laser_indicator(
+    laser_query: Query<&LaserCannon, With<Paddle>>,
+    mut indicator_query: Query<&mut Text, With<LaserIndicator>>,
+) {
+    let remaining_seconds = laser_query
+        .iter()
+        .map(|laser_cannon| laser_cannon.remaining_seconds)
+        .fold(0.0, f32::max);
+    for mut text in &mut indicator_query {
+        text.sections[0].value = if remaining_seconds > 0.0 {
+            format!("Laser {:.1}s", remaining_seconds)
+        } else {
+            String::new()
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_laser_cannon_fire_rate() {
+        let policy = LaserPolicy::default();
+        let mut laser_cannon = LaserCannon {
+            remaining_seconds: 1.0,
+            ..default()
+        };
+        let delta_seconds = 1.0 / 64.0;
+
+        let fired = (0..128)
+            .filter(|_| laser_cannon.tick(delta_seconds, true, &policy))
+            .count();
+
+        // Fires immediately, then once per interval until the laser runs out after 1 second
+        let expected = 1 + (1.0 / policy.fire_interval_seconds) as usize;
+        assert_eq!(fired, expected);
+        assert!(!laser_cannon.is_active());
+    }
+
+    #[test]
+    fn test_inactive_laser_cannon_does_not_fire() {
+        let policy = LaserPolicy::default();
+        let mut laser_cannon = LaserCannon::default();
+
+        assert!(!laser_cannon.tick(1.0, true, &policy));
+    }
+}
@@ -0,0 +1,115 @@
+use bevy::prelude::*;
+
+use super::{
+    anti_loop::AntiLoopPolicy,
+    ball_speed::BallSpeedPolicy,
+    barrier::BarrierPolicy,
+    catch::CatchPolicy,
+    fireball::FireballPolicy,
+    laser::LaserPolicy,
+    loot::{LootEntry, LootTable},
+    moving_brick::{MotionPath, MovingBrickSpec},
+    paddle_width::PaddleWidthPolicy,
+    power_up::PowerUpKind,
+    score::ScorePolicy,
+    slow_motion::{BulletTimePolicy, SlowBallPolicy},
+    wall::BOTTOM_WALL,
+};
+
+const MOVING_BRICK_COLOR: Color = Color::rgb(0.7, 0.4, 1.0);
+
+/**
+ * Settings of the level being played.
+ * Insert a different `Level` before entering `AppState::Game` to override the defaults.
+ */
+#[derive(Resource, Clone)]
+pub struct Level {
+    pub ball_speed: BallSpeedPolicy,
+    pub score: ScorePolicy,
+    pub anti_loop: AntiLoopPolicy,
+    pub paddle_width: PaddleWidthPolicy,
+    pub laser: LaserPolicy,
+    pub catch: CatchPolicy,
+    pub fireball: FireballPolicy,
+    pub barrier: BarrierPolicy,
+    pub slow_ball: SlowBallPolicy,
+    pub bullet_time: BulletTimePolicy,
+    pub moving_bricks: Vec<MovingBrickSpec>,
+    /// Grid coordinates (column, row) of explosive bricks in the brick block
+    pub explosive_bricks: Vec<UVec2>,
+    pub explosion_radius: f32,
+    pub loot: LootTable,
+}
+
+impl Default for Level {
+    fn default() -> Self {
+        Self {
+            ball_speed: BallSpeedPolicy::default(),
+            score: ScorePolicy::default(),
+            anti_loop: AntiLoopPolicy::default(),
+            paddle_width: PaddleWidthPolicy::default(),
+            laser: LaserPolicy::default(),
+            catch: CatchPolicy::default(),
+            fireball: FireballPolicy::default(),
+            barrier: BarrierPolicy::default(),
+            slow_ball: SlowBallPolicy::default(),
+            bullet_time: BulletTimePolicy::default(),
+            moving_bricks: vec![MovingBrickSpec {
+                size: Vec2::new(60., 30.),
+                color: MOVING_BRICK_COLOR,
+                path: MotionPath::Patrol {
+                    points: vec![
+                        Vec2::new(-300., BOTTOM_WALL + 220.),
+                        Vec2::new(300., BOTTOM_WALL + 220.),
+                    ],
+                    speed: 100.,
+                },
+            }],
+            explosive_bricks: vec![
+                UVec2::new(2, 1),
+                UVec2::new(3, 2),
+                UVec2::new(8, 3),
+                UVec2::new(5, 6),
+            ],
+            explosion_radius: 80.,
+            loot: LootTable {
+                seed: 0xB12E_A4E5,
+                drop_chance: 0.15,
+                entries: vec![
+                    LootEntry {
+                        kind: PowerUpKind::ExpandPaddle,
+                        weight: 4,
+                    },
+                    LootEntry {
+                        kind: PowerUpKind::ShrinkPaddle,
+                        weight: 2,
+                    },
+                    LootEntry {
+                        kind: PowerUpKind::Laser,
+                        weight: 2,
+                    },
+                    LootEntry {
+                        kind: PowerUpKind::Catch,
+                        weight: 2,
+                    },
+                    LootEntry {
+                        kind: PowerUpKind::Fireball,
+                        weight: 1,
+                    },
+                    LootEntry {
+                        kind: PowerUpKind::Barrier,
+                        weight: 1,
+                    },
+                    LootEntry {
+                        kind: PowerUpKind::SlowBall,
+                        weight: 2,
+                    },
+                ],
+                guaranteed_drops: vec![
+                    (UVec2::new(0, 4), PowerUpKind::ExpandPaddle),
+                    (UVec2::new(10, 4), PowerUpKind::Catch),
+                ],
+            },
+        }
+    }
+}
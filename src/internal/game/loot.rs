@@ -0,0 +1,162 @@
+use bevy::prelude::*;
+
+use super::{
+    game::GameState,
+    gameplay_events::{BrickDestroyCause, BrickDestroyed},
+    level::Level,
+    power_up::{PowerUpFactory, PowerUpKind},
+};
+
+#[derive(Debug, Clone, Copy)]
+pub struct LootEntry {
+    pub kind: PowerUpKind,
+    pub weight: u32,
+}
+
+/**
+ * Decides which power-up a destroyed brick drops.
+ * Bricks listed in `guaranteed_drops` (by grid coordinates) always drop their power-up,
+ * any other brick drops with `drop_chance`, picking from `entries` by weight.
+ */
+#[derive(Debug, Clone, Default)]
+pub struct LootTable {
+    pub seed: u64,
+    pub drop_chance: f32,
+    pub entries: Vec<LootEntry>,
+    pub guaranteed_drops: Vec<(UVec2, PowerUpKind)>,
+}
+
+impl LootTable {
+    pub fn roll(&self, rng: &mut LootRng, coordinates: Option<UVec2>) -> Option<PowerUpKind> {
+        if let Some((_, kind)) = self
+            .guaranteed_drops
+            .iter()
+            .find(|(guaranteed, _)| Some(*guaranteed) == coordinates)
+        {
+            return Some(*kind);
+        }
+
+        if rng.next_f32() >= self.drop_chance {
+            return None;
+        }
+
+        let total_weight: u32 = self.entries.iter().map(|entry| entry.weight).sum();
+        if total_weight == 0 {
+            return None;
+        }
+        let mut pick = rng.next_u64() % total_weight as u64;
+        for entry in &self.entries {
+            if pick < entry.weight as u64 {
+                return Some(entry.kind);
+            }
+            pick -= entry.weight as u64;
+        }
+        None
+    }
+}
+
+/// Small xorshift64* generator, so drops are reproducible from the level's seed on every platform
+#[derive(Resource, Debug, Clone)]
+pub struct LootRng(u64);
+
+impl LootRng {
+    pub fn new(seed: u64) -> Self {
+        // xorshift gets stuck on zero
+        Self(seed ^ 0x9E37_79B9_7F4A_7C15)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    /// Uniform in `[0, 1)`
+    pub fn next_f32(&mut self) -> f32 {
+        (self.next_u64() >> 40) as f32 / (1u64 << 24) as f32
+    }
+}
+
+pub fn handle_loot_events(
+    mut commands: Commands,
+    mut ev_brick_destroyed: EventReader<BrickDestroyed>,
+    mut rng: ResMut<LootRng>,
+    level: Res<Level>,
+) {
+    for brick in ev_brick_destroyed.read() {
+        // Bricks caught in explosions don't drop loot
+        if !matches!(brick.cause, BrickDestroyCause::Hit { .. }) {
+            continue;
+        }
+        if let Some(kind) = level.loot.roll(&mut rng, brick.coordinates) {
+            let power_up = PowerUpFactory {
+                kind,
+                position: brick.position,
+            }
+            .spawn(&mut commands);
+            commands.entity(power_up).insert(GameState);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rstest::rstest;
+
+    fn table(drop_chance: f32) -> LootTable {
+        LootTable {
+            seed: 7,
+            drop_chance,
+            entries: vec![
+                LootEntry {
+                    kind: PowerUpKind::ExpandPaddle,
+                    weight: 3,
+                },
+                LootEntry {
+                    kind: PowerUpKind::ShrinkPaddle,
+                    weight: 1,
+                },
+            ],
+            guaranteed_drops: vec![(UVec2::new(1, 2), PowerUpKind::ShrinkPaddle)],
+        }
+    }
+
+    #[rstest]
+    #[case(0.0, Some(UVec2::new(1, 2)), Some(PowerUpKind::ShrinkPaddle))]
+    #[case(0.0, Some(UVec2::new(2, 1)), None)]
+    #[case(0.0, None, None)]
+    fn test_loot_table_roll(
+        #[case] drop_chance: f32,
+        #[case] coordinates: Option<UVec2>,
+        #[case] expected: Option<PowerUpKind>,
+    ) {
+        let mut rng = LootRng::new(1);
+
+        assert_eq!(table(drop_chance).roll(&mut rng, coordinates), expected);
+    }
+
+    #[test]
+    fn test_loot_table_weights() {
+        let table = table(1.0);
+        let mut rng = LootRng::new(table.seed);
+        let expand_count = (0..4000)
+            .filter(|_| table.roll(&mut rng, None) == Some(PowerUpKind::ExpandPaddle))
+            .count();
+
+        assert!((2800..3200).contains(&expand_count), "{expand_count}");
+    }
+
+    #[test]
+    fn test_loot_rng_is_seeded() {
+        let rolls = |seed| {
+            let mut rng = LootRng::new(seed);
+            (0..16).map(|_| rng.next_u64()).collect::<Vec<_>>()
+        };
+
+        assert_eq!(rolls(42), rolls(42));
+        assert_ne!(rolls(42), rolls(43));
+    }
+}
@@ -1,7 +1,24 @@
 pub mod game;
 
 pub mod score;
+pub mod ball;
+pub mod ball_speed;
+pub mod barrier;
+pub mod catch;
+pub mod anti_loop;
+pub mod level;
 pub mod brick;
+pub mod moving_brick;
+pub mod explosive;
+pub mod fireball;
+pub mod gameplay_events;
+pub mod impact;
+pub mod loot;
+pub mod power_up;
 pub mod wall;
+pub mod laser;
 pub mod paddle;
+pub mod paddle_width;
+pub mod slow_motion;
+pub mod sound_effects;
 pub mod destroy_on_collision;
\ No newline at end of file
@@ -0,0 +1,233 @@
+use bevy::prelude::*;
+
+use crate::internal::physics::{GameTime, RigidBody, TransformInterpolation, Velocity};
+
+use super::brick::BrickBundle;
+
+#[derive(Debug, Clone)]
+pub enum MotionPath {
+    /// Moves back and forth along the polyline through `points`
+    Patrol { points: Vec<Vec2>, speed: f32 },
+    /// Circles around `center`, `angular_speed` in radians per second
+    Orbit {
+        center: Vec2,
+        radius: f32,
+        angular_speed: f32,
+        phase: f32,
+    },
+    /// Loops along a closed Catmull-Rom spline through `points` once every `duration` seconds,
+    /// stays on the first point when `duration` is not positive
+    Spline { points: Vec<Vec2>, duration: f32 },
+}
+
+impl MotionPath {
+    pub fn position_at(&self, time: f32) -> Vec2 {
+        match self {
+            MotionPath::Patrol { points, speed } => patrol_position(points, time * speed),
+            MotionPath::Orbit {
+                center,
+                radius,
+                angular_speed,
+                phase,
+            } => *center + Vec2::from_angle(phase + angular_speed * time) * *radius,
+            MotionPath::Spline { points, duration } => {
+                let progress = if *duration > 0.0 {
+                    (time / duration).rem_euclid(1.0)
+                } else {
+                    0.0
+                };
+                spline_position(points, progress)
+            }
+        }
+    }
+}
+
+fn patrol_position(points: &[Vec2], distance: f32) -> Vec2 {
+    let total_length: f32 = points
+        .windows(2)
+        .map(|pair| pair[0].distance(pair[1]))
+        .sum();
+    if total_length == 0.0 {
+        return points.first().copied().unwrap_or_default();
+    }
+
+    // Going forth and back makes one period twice the length of the path
+    let mut distance = distance.rem_euclid(total_length * 2.0);
+    if distance > total_length {
+        distance = total_length * 2.0 - distance;
+    }
+
+    for pair in points.windows(2) {
+        let segment_length = pair[0].distance(pair[1]);
+        // Repeated points make empty segments that cannot be interpolated along
+        if segment_length == 0.0 {
+            continue;
+        }
+        if distance <= segment_length {
+            return pair[0].lerp(pair[1], distance / segment_length);
+        }
+        distance -= segment_length;
+    }
+    *points.last().unwrap()
+}
+
+fn spline_position(points: &[Vec2], progress: f32) -> Vec2 {
+    let count = points.len();
+    if count < 2 {
+        return points.first().copied().unwrap_or_default();
+    }
+
+    let position = progress * count as f32;
+    let segment = (position.floor() as usize).min(count - 1);
+    let t = position - segment as f32;
+    let point = |offset: usize| points[(segment + count + offset - 1) % count];
+    let (p0, p1, p2, p3) = (point(0), point(1), point(2), point(3));
+
+    let t2 = t * t;
+    let t3 = t2 * t;
+    0.5 * (2.0 * p1
+        + (p2 - p0) * t
+        + (2.0 * p0 - 5.0 * p1 + 4.0 * p2 - p3) * t2
+        + (3.0 * p1 - p0 - 3.0 * p2 + p3) * t3)
+}
+
+#[derive(Debug, Clone)]
+pub struct MovingBrickSpec {
+    pub size: Vec2,
+    pub color: Color,
+    pub path: MotionPath,
+}
+
+#[derive(Component)]
+pub struct BrickMotion {
+    pub path: MotionPath,
+    pub elapsed: f32,
+}
+
+pub struct MovingBrickFactory<'a> {
+    pub specs: &'a [MovingBrickSpec],
+}
+
+impl MovingBrickFactory<'_> {
+    pub fn spawn(&self, parent: &mut ChildBuilder) {
+        for spec in self.specs {
+            parent.spawn((
+                BrickBundle::new(spec.path.position_at(0.0), spec.size, spec.color),
+                RigidBody::Kinematic,
+                Velocity(Vec2::ZERO),
+                TransformInterpolation::default(),
+                BrickMotion {
+                    path: spec.path.clone(),
+                    elapsed: 0.0,
+                },
+            ));
+        }
+    }
+}
+
+/// Sets the velocity that takes each brick to its next position on the path during the coming tick
+pub fn move_bricks(
+    mut query: Query<(&Transform, &mut Velocity, &mut BrickMotion)>,
+    time: GameTime,
+) {
+    let delta_seconds = time.delta_seconds();
+    if delta_seconds == 0.0 {
+        return;
+    }
+    for (transform, mut velocity, mut motion) in &mut query {
+        motion.elapsed += delta_seconds;
+        let target = motion.path.position_at(motion.elapsed);
+        velocity.0 = (target - transform.translation.truncate()) / delta_seconds;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rstest::rstest;
+
+    fn square() -> Vec<Vec2> {
+        vec![
+            Vec2::new(0.0, 0.0),
+            Vec2::new(10.0, 0.0),
+            Vec2::new(10.0, 10.0),
+            Vec2::new(0.0, 10.0),
+        ]
+    }
+
+    #[rstest]
+    #[case(
+        MotionPath::Patrol { points: vec![Vec2::ZERO, Vec2::new(10.0, 0.0)], speed: 5.0 },
+        1.0,
+        Vec2::new(5.0, 0.0)
+    )]
+    #[case(
+        MotionPath::Patrol { points: vec![Vec2::ZERO, Vec2::new(10.0, 0.0)], speed: 5.0 },
+        3.0,
+        Vec2::new(5.0, 0.0)
+    )]
+    #[case(
+        MotionPath::Patrol { points: square(), speed: 1.0 },
+        15.0,
+        Vec2::new(10.0, 5.0)
+    )]
+    #[case(
+        MotionPath::Orbit { center: Vec2::new(10.0, 0.0), radius: 5.0, angular_speed: std::f32::consts::PI, phase: 0.0 },
+        0.5,
+        Vec2::new(10.0, 5.0)
+    )]
+    #[case(
+        MotionPath::Spline { points: square(), duration: 4.0 },
+        0.0,
+        Vec2::new(0.0, 0.0)
+    )]
+    #[case(
+        MotionPath::Spline { points: square(), duration: 4.0 },
+        6.0,
+        Vec2::new(10.0, 10.0)
+    )]
+    fn test_motion_path_position(
+        #[case] path: MotionPath,
+        #[case] time: f32,
+        #[case] expected: Vec2,
+    ) {
+        let position = path.position_at(time);
+
+        assert!(
+            position.abs_diff_eq(expected, 1e-4),
+            "{position} != {expected}"
+        );
+    }
+
+    #[rstest]
+    #[case(0.0, Vec2::ZERO)]
+    #[case(1.0, Vec2::new(5.0, 0.0))]
+    #[case(2.0, Vec2::new(10.0, 0.0))]
+    fn test_patrol_skips_repeated_points(#[case] time: f32, #[case] expected: Vec2) {
+        let path = MotionPath::Patrol {
+            points: vec![
+                Vec2::ZERO,
+                Vec2::ZERO,
+                Vec2::new(10.0, 0.0),
+                Vec2::new(10.0, 0.0),
+            ],
+            speed: 5.0,
+        };
+
+        assert_eq!(path.position_at(time), expected);
+    }
+
+    #[rstest]
+    #[case(0.0)]
+    #[case(-1.0)]
+    fn test_spline_without_duration_stays_on_first_point(#[case] duration: f32) {
+        let path = MotionPath::Spline {
+            points: square(),
+            duration,
+        };
+
+        assert_eq!(path.position_at(0.0), Vec2::ZERO);
+        assert_eq!(path.position_at(2.5), Vec2::ZERO);
+    }
+}
@@ -1,20 +1,31 @@
 use bevy::prelude::*;
 
 use crate::internal::physics::{
-    CollisionBody, CollisionGroup, CollisionMask, CollisionProperties, CurvedRectBounce,
+    CollisionBody, CollisionGroup, CollisionMask, CollisionProperties, CurvedRectBounce, GameTime,
+    MomentumTransfer, RigidBody, TransformInterpolation, UnscaledTime, Velocity,
 };
 
-use super::wall::{BOTTOM_WALL, LEFT_WALL, RIGHT_WALL, WALL_THICKNESS};
+use super::{
+    catch::CatchPaddle,
+    laser::LaserCannon,
+    paddle_width::PaddleWidth,
+    wall::{BOTTOM_WALL, LEFT_WALL, RIGHT_WALL, WALL_THICKNESS},
+};
 
 const PADDLE_PADDING: f32 = 10.0;
 const PADDLE_SPEED: f32 = 500.0;
 const PADDLE_COLOR: Color = Color::rgb(0.3, 0.3, 0.7);
 const GAP_BETWEEN_PADDLE_AND_FLOOR: f32 = 60.0;
+const PADDLE_FRICTION: f32 = 0.6;
+// Fraction of the paddle's velocity the ball picks up on contact
+const PADDLE_MOMENTUM_TRANSFER: f32 = 0.2;
 
 #[derive(Component)]
 pub struct Paddle;
 
-pub struct PaddleFactory;
+pub struct PaddleFactory {
+    pub width: f32,
+}
 
 impl PaddleFactory {
     pub fn spawn(&self, parent: &mut ChildBuilder) {
@@ -22,7 +33,7 @@ impl PaddleFactory {
             SpriteBundle {
                 transform: Transform {
                     translation: Vec3::new(0.0, BOTTOM_WALL + GAP_BETWEEN_PADDLE_AND_FLOOR, 0.0),
-                    scale: Vec3::new(120.0, 20.0, 0.0),
+                    scale: Vec3::new(self.width, 20.0, 0.0),
                     ..default()
                 },
                 sprite: Sprite {
@@ -32,10 +43,20 @@ impl PaddleFactory {
                 ..default()
             },
             Paddle,
+            PaddleWidth::default(),
+            LaserCannon::default(),
+            CatchPaddle::default(),
+            TransformInterpolation::default(),
+            RigidBody::Kinematic,
+            Velocity(Vec2::ZERO),
+            // The paddle follows the player in real time, even in slow motion
+            UnscaledTime,
+            MomentumTransfer(PADDLE_MOMENTUM_TRANSFER),
             CollisionProperties {
                 body: CollisionBody::Rect,
                 group: CollisionMask::new(&[CollisionGroup::Paddle]),
                 mask: CollisionMask::new(&[CollisionGroup::Ball, CollisionGroup::Powerup]),
+                friction: PADDLE_FRICTION,
                 ..default()
             },
             CurvedRectBounce {
@@ -47,27 +68,127 @@ impl PaddleFactory {
 
 pub fn move_paddle(
     keyboard_input: Res<Input<KeyCode>>,
-    mut query: Query<&mut Transform, With<Paddle>>,
-    time: Res<Time>,
+    mut query: Query<(&Transform, &mut Velocity), With<Paddle>>,
+    time: GameTime,
 ) {
-    let mut paddle_transform = query.single_mut();
+    let (paddle_transform, mut paddle_velocity) = query.single_mut();
     let paddle_size = paddle_transform.scale.truncate();
+    // Without input the paddle still gets clamped, so a growing paddle is pushed away from the walls
     let direction = if keyboard_input.pressed(KeyCode::Left) {
         -1.0
     } else if keyboard_input.pressed(KeyCode::Right) {
         1.0
     } else {
-        return;
+        0.0
     };
 
-    // Calculate the new horizontal paddle position based on player input
+    // Calculate the new horizontal paddle position based on player input,
+    // in real time so the paddle stays responsive in slow motion
     let new_paddle_position =
-        paddle_transform.translation.x + direction * PADDLE_SPEED * time.delta_seconds();
+        paddle_transform.translation.x + direction * PADDLE_SPEED * time.unscaled_delta_seconds();
 
-    // Update the paddle position,
+    // Set the paddle velocity so physics moves it to the new position,
     // making sure it doesn't cause the paddle to leave the arena
     let left_bound = LEFT_WALL + WALL_THICKNESS / 2.0 + paddle_size.x / 2.0 + PADDLE_PADDING;
     let right_bound = RIGHT_WALL - WALL_THICKNESS / 2.0 - paddle_size.x / 2.0 - PADDLE_PADDING;
 
-    paddle_transform.translation.x = new_paddle_position.clamp(left_bound, right_bound);
+    let clamped_paddle_position = new_paddle_position.clamp(left_bound, right_bound);
+    paddle_velocity.0 = Vec2::ZERO;
+    if time.unscaled_delta_seconds() > 0.0 {
+        paddle_velocity.x = (clamped_paddle_position - paddle_transform.translation.x)
+            / time.unscaled_delta_seconds();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::ecs::system::CommandQueue;
+
+    use super::*;
+
+    use rstest::rstest;
+
+    use crate::internal::physics::{PhysicsLabel, PhysicsPlugin, TimeScale, DEFAULT_TICK_RATE};
+
+    const BALL_SPEED: f32 = 300.0;
+
+    fn paddle_app(time_scale: f32) -> App {
+        let mut app = App::new();
+        app.add_plugins(PhysicsPlugin)
+            .insert_resource(TimeScale(time_scale))
+            .add_systems(FixedUpdate, move_paddle.before(PhysicsLabel));
+        let mut time = Time::<()>::default();
+        time.advance_by(Duration::from_secs_f64(1. / DEFAULT_TICK_RATE));
+        app.insert_resource(time);
+        let mut input = Input::<KeyCode>::default();
+        input.press(KeyCode::Right);
+        app.insert_resource(input);
+
+        let mut queue = CommandQueue::default();
+        Commands::new(&mut queue, &app.world)
+            .spawn_empty()
+            .with_children(|parent| PaddleFactory { width: 120.0 }.spawn(parent));
+        queue.apply(&mut app.world);
+        app
+    }
+
+    #[rstest]
+    #[case(1.0)]
+    #[case(0.25)]
+    fn test_paddle_moves_in_real_time(#[case] time_scale: f32) {
+        let mut app = paddle_app(time_scale);
+        let ticks = 8;
+
+        for _ in 0..ticks {
+            app.world.run_schedule(FixedUpdate);
+        }
+
+        let x = app
+            .world
+            .query_filtered::<&Transform, With<Paddle>>()
+            .single(&app.world)
+            .translation
+            .x;
+        let expected = PADDLE_SPEED * ticks as f32 / DEFAULT_TICK_RATE as f32;
+        assert!((x - expected).abs() < 1e-3, "{x} != {expected}");
+    }
+
+    #[rstest]
+    #[case(1.0)]
+    #[case(0.25)]
+    fn test_slow_motion_does_not_boost_paddle_hits(#[case] time_scale: f32) {
+        let mut app = paddle_app(time_scale);
+        let paddle_top = BOTTOM_WALL + GAP_BETWEEN_PADDLE_AND_FLOOR + 10.0;
+        let ball = app
+            .world
+            .spawn((
+                Transform::from_xyz(0.0, paddle_top + 10.0, 0.0)
+                    .with_scale(Vec3::new(10.0, 10.0, 1.0)),
+                RigidBody::Dynamic,
+                Velocity(Vec2::new(0.0, -BALL_SPEED)),
+                CollisionProperties {
+                    body: CollisionBody::Circle,
+                    group: CollisionMask::new(&[CollisionGroup::Ball]),
+                    mask: CollisionMask::new(&[CollisionGroup::Paddle]),
+                    ..default()
+                },
+            ))
+            .id();
+
+        for _ in 0..256 {
+            app.world.run_schedule(FixedUpdate);
+            if app.world.get::<Velocity>(ball).unwrap().y > 0.0 {
+                break;
+            }
+        }
+
+        let velocity = app.world.get::<Velocity>(ball).unwrap().0;
+        assert!(velocity.y > 0.0, "{velocity}");
+        assert!(
+            velocity.length() <= BALL_SPEED + PADDLE_MOMENTUM_TRANSFER * PADDLE_SPEED + 1e-3,
+            "{velocity}"
+        );
+    }
 }
@@ -0,0 +1,139 @@
+use bevy::prelude::*;
+
+use crate::internal::physics::GameTime;
+
+use super::{
+    gameplay_events::PowerUpCollected, level::Level, paddle::Paddle, power_up::PowerUpKind,
+};
+
+#[derive(Debug, Clone, Copy)]
+pub struct PaddleWidthPolicy {
+    pub base_width: f32,
+    /// Width multiplier applied per expand stack, divided per shrink stack
+    pub step: f32,
+    /// How many expand (or shrink) power-ups stack on top of each other
+    pub max_stacks: i32,
+    /// Seconds the effect lasts, refreshed by every collected expand or shrink
+    pub duration_seconds: f32,
+    /// Width change per second while the paddle animates towards its target width
+    pub resize_speed: f32,
+}
+
+impl Default for PaddleWidthPolicy {
+    fn default() -> Self {
+        Self {
+            base_width: 120.0,
+            step: 1.4,
+            max_stacks: 2,
+            duration_seconds: 12.0,
+            resize_speed: 240.0,
+        }
+    }
+}
+
+/**
+ * Active expand / shrink effect of the paddle.
+ * Expands and shrinks cancel each other out, stacks of the same kind multiply the width.
+ */
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq)]
+pub struct PaddleWidth {
+    /// Positive for expanded, negative for shrunk
+    pub stacks: i32,
+    pub remaining_seconds: f32,
+}
+
+impl PaddleWidth {
+    pub fn collect(&mut self, kind: PowerUpKind, policy: &PaddleWidthPolicy) {
+        let change = match kind {
+            PowerUpKind::ExpandPaddle => 1,
+            PowerUpKind::ShrinkPaddle => -1,
+            _ => return,
+        };
+        self.stacks = (self.stacks + change).clamp(-policy.max_stacks, policy.max_stacks);
+        self.remaining_seconds = policy.duration_seconds;
+    }
+
+    pub fn tick(&mut self, delta_seconds: f32) {
+        if self.stacks == 0 {
+            return;
+        }
+        self.remaining_seconds -= delta_seconds;
+        if self.remaining_seconds <= 0.0 {
+            *self = Self::default();
+        }
+    }
+
+    pub fn target_width(&self, policy: &PaddleWidthPolicy) -> f32 {
+        policy.base_width * policy.step.powi(self.stacks)
+    }
+}
+
+pub fn handle_paddle_width_power_ups(
+    mut ev_power_up: EventReader<PowerUpCollected>,
+    mut query: Query<&mut PaddleWidth, With<Paddle>>,
+    level: Res<Level>,
+) {
+    for power_up in ev_power_up.read() {
+        for mut paddle_width in &mut query {
+            paddle_width.collect(power_up.kind, &level.paddle_width);
+        }
+    }
+}
+
+/// Animates the paddle width towards its target, `move_paddle` keeps the resized paddle inside the walls
+pub fn update_paddle_width(
+    mut query: Query<(&mut Transform, &mut PaddleWidth), With<Paddle>>,
+    level: Res<Level>,
+    time: GameTime,
+) {
+    let policy = &level.paddle_width;
+    for (mut transform, mut paddle_width) in &mut query {
+        paddle_width.tick(time.delta_seconds());
+
+        let target_width = paddle_width.target_width(policy);
+        let max_change = policy.resize_speed * time.delta_seconds();
+        transform.scale.x += (target_width - transform.scale.x).clamp(-max_change, max_change);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(&[PowerUpKind::ExpandPaddle], 1)]
+    #[case(&[PowerUpKind::ExpandPaddle, PowerUpKind::ExpandPaddle, PowerUpKind::ExpandPaddle], 2)]
+    #[case(&[PowerUpKind::ExpandPaddle, PowerUpKind::ShrinkPaddle], 0)]
+    #[case(&[PowerUpKind::ShrinkPaddle, PowerUpKind::ShrinkPaddle, PowerUpKind::ShrinkPaddle], -2)]
+    #[case(&[PowerUpKind::Laser], 0)]
+    fn test_paddle_width_stacks(#[case] collected: &[PowerUpKind], #[case] expected_stacks: i32) {
+        let policy = PaddleWidthPolicy::default();
+        let mut paddle_width = PaddleWidth::default();
+        for kind in collected {
+            paddle_width.collect(*kind, &policy);
+        }
+
+        assert_eq!(paddle_width.stacks, expected_stacks);
+        assert_eq!(
+            paddle_width.target_width(&policy),
+            policy.base_width * policy.step.powi(expected_stacks)
+        );
+    }
+
+    #[test]
+    fn test_paddle_width_refresh_and_expiry() {
+        let policy = PaddleWidthPolicy::default();
+        let mut paddle_width = PaddleWidth::default();
+
+        paddle_width.collect(PowerUpKind::ExpandPaddle, &policy);
+        paddle_width.tick(policy.duration_seconds - 1.0);
+        paddle_width.collect(PowerUpKind::ExpandPaddle, &policy);
+        paddle_width.tick(policy.duration_seconds - 1.0);
+        assert_eq!(paddle_width.stacks, 2);
+
+        paddle_width.tick(1.0);
+        assert_eq!(paddle_width, PaddleWidth::default());
+    }
+}
@@ -0,0 +1,79 @@
+use bevy::prelude::*;
+
+use crate::internal::physics::{
+    CollisionBody, CollisionGroup, CollisionMask, CollisionProperties, LocalGravity, RigidBody,
+    SpeedLimit, TransformInterpolation, Velocity,
+};
+
+const POWER_UP_SIZE: Vec2 = Vec2::new(30.0, 15.0);
+const POWER_UP_GRAVITY: Vec2 = Vec2::new(0.0, -300.0);
+const POWER_UP_MAX_FALL_SPEED: f32 = 250.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PowerUpKind {
+    ExpandPaddle,
+    ShrinkPaddle,
+    Laser,
+    Catch,
+    Fireball,
+    Barrier,
+    SlowBall,
+}
+
+impl PowerUpKind {
+    pub fn color(&self) -> Color {
+        match self {
+            PowerUpKind::ExpandPaddle => Color::rgb(0.3, 0.8, 0.3),
+            PowerUpKind::ShrinkPaddle => Color::rgb(0.8, 0.3, 0.3),
+            PowerUpKind::Laser => Color::rgb(0.9, 0.2, 0.6),
+            PowerUpKind::Catch => Color::rgb(0.3, 0.6, 0.9),
+            PowerUpKind::Fireball => Color::rgb(1.0, 0.5, 0.0),
+            PowerUpKind::Barrier => Color::rgb(0.5, 0.9, 0.9),
+            PowerUpKind::SlowBall => Color::rgb(0.7, 0.7, 0.2),
+        }
+    }
+}
+
+#[derive(Component)]
+pub struct PowerUp(pub PowerUpKind);
+
+pub struct PowerUpFactory {
+    pub kind: PowerUpKind,
+    pub position: Vec2,
+}
+
+impl PowerUpFactory {
+    pub fn spawn(&self, commands: &mut Commands) -> Entity {
+        commands
+            .spawn((
+                SpriteBundle {
+                    sprite: Sprite {
+                        color: self.kind.color(),
+                        ..default()
+                    },
+                    transform: Transform {
+                        translation: self.position.extend(0.5),
+                        scale: POWER_UP_SIZE.extend(1.0),
+                        ..default()
+                    },
+                    ..default()
+                },
+                PowerUp(self.kind),
+                RigidBody::Dynamic,
+                Velocity(Vec2::ZERO),
+                LocalGravity(POWER_UP_GRAVITY),
+                SpeedLimit {
+                    min: 0.0,
+                    max: POWER_UP_MAX_FALL_SPEED,
+                },
+                TransformInterpolation::default(),
+                CollisionProperties {
+                    body: CollisionBody::Rect,
+                    group: CollisionMask::new(&[CollisionGroup::Powerup]),
+                    mask: CollisionMask::new(&[CollisionGroup::Paddle, CollisionGroup::Wall]),
+                    ..default()
+                },
+            ))
+            .id()
+    }
+}
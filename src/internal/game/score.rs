@@ -1,50 +1,228 @@
-use bevy::{prelude::*, utils::HashSet};
+use bevy::prelude::*;
 
-use super::super::physics::CollisionEvent;
+use crate::internal::{effects::effects::EffectEvent, physics::GameTime};
+
+use super::{
+    gameplay_events::{BallLost, BrickDestroyCause, BrickDestroyed, PaddleHit},
+    level::Level,
+};
 
 const SCOREBOARD_FONT_SIZE: f32 = 40.0;
 const SCOREBOARD_TEXT_PADDING: Val = Val::Px(5.0);
 
 const TEXT_COLOR: Color = Color::rgb(0.5, 0.5, 1.0);
 const SCORE_COLOR: Color = Color::rgb(1.0, 0.5, 0.5);
+const MULTIPLIER_COLOR: Color = Color::rgb(1.0, 0.6, 0.2);
+
+#[derive(Debug, Clone, Copy)]
+pub struct ScorePolicy {
+    /// Multiplier gained for every brick hit in a row
+    pub multiplier_per_brick: f32,
+    pub max_multiplier: f32,
+    /// Multiplier lost per second, down to 1
+    pub multiplier_decay_per_second: f32,
+    /// Clearing the level faster than this awards `time_bonus_per_second` for every second left
+    pub par_seconds: f32,
+    pub time_bonus_per_second: usize,
+    pub no_life_lost_bonus: usize,
+}
+
+impl Default for ScorePolicy {
+    fn default() -> Self {
+        Self {
+            multiplier_per_brick: 0.1,
+            max_multiplier: 3.0,
+            multiplier_decay_per_second: 0.25,
+            par_seconds: 180.0,
+            time_bonus_per_second: 10,
+            no_life_lost_bonus: 1000,
+        }
+    }
+}
+
+/// Where the points came from
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ScoreBreakdown {
+    pub bricks: usize,
+    pub combo_bonus: usize,
+    pub chain_bonus: usize,
+    pub time_bonus: usize,
+    pub no_life_lost_bonus: usize,
+}
+
+impl ScoreBreakdown {
+    pub fn total(&self) -> usize {
+        self.bricks
+            + self.combo_bonus
+            + self.chain_bonus
+            + self.time_bonus
+            + self.no_life_lost_bonus
+    }
+}
 
-#[derive(Resource)]
+/**
+ * Score of the level being played.
+ * Bricks hit without touching the paddle in between build a combo and raise the multiplier,
+ * which decays back to 1 over time.
+ */
+#[derive(Resource, Debug, Clone)]
 pub struct Scoreboard {
-    pub score: usize,
+    pub breakdown: ScoreBreakdown,
+    pub combo: usize,
+    pub longest_combo: usize,
+    pub multiplier: f32,
+    pub lives_lost: usize,
+    /// Real seconds spent on the level, slow motion and hit-stop don't stop the par clock
+    pub elapsed_seconds: f32,
+}
+
+impl Default for Scoreboard {
+    fn default() -> Self {
+        Self {
+            breakdown: ScoreBreakdown::default(),
+            combo: 0,
+            longest_combo: 0,
+            multiplier: 1.0,
+            lives_lost: 0,
+            elapsed_seconds: 0.0,
+        }
+    }
+}
+
+impl Scoreboard {
+    pub fn score(&self) -> usize {
+        self.breakdown.total()
+    }
+
+    /// Scores a brick hit with the current multiplier, then raises the multiplier, returns the awarded points
+    pub fn add_brick(&mut self, points: usize, policy: &ScorePolicy) -> usize {
+        let awarded = (points as f32 * self.multiplier).round() as usize;
+        self.breakdown.bricks += points;
+        self.breakdown.combo_bonus += awarded.saturating_sub(points);
+
+        self.combo += 1;
+        self.longest_combo = self.longest_combo.max(self.combo);
+        self.multiplier =
+            (self.multiplier + policy.multiplier_per_brick).min(policy.max_multiplier);
+        awarded
+    }
+
+    /// Scores a brick destroyed by the `chain_length`th explosion of a chain reaction, returns the awarded points
+    pub fn add_chain(&mut self, points: usize, chain_length: usize) -> usize {
+        self.breakdown.bricks += points;
+        self.breakdown.chain_bonus += points * chain_length.saturating_sub(1);
+        points * chain_length
+    }
+
+    /// Ends the combo, the next brick is scored without a multiplier
+    pub fn touch_paddle(&mut self) {
+        self.combo = 0;
+        self.multiplier = 1.0;
+    }
+
+    pub fn lose_life(&mut self) {
+        self.lives_lost += 1;
+        self.combo = 0;
+        self.multiplier = 1.0;
+    }
+
+    /// Decays the multiplier by game time
+    pub fn decay_multiplier(&mut self, delta_seconds: f32, policy: &ScorePolicy) {
+        self.multiplier =
+            (self.multiplier - policy.multiplier_decay_per_second * delta_seconds).max(1.0);
+    }
+
+    /// Advances the par clock by real time
+    pub fn tick_clock(&mut self, real_delta_seconds: f32) {
+        self.elapsed_seconds += real_delta_seconds;
+    }
+
+    /// Awards the level completion bonuses
+    pub fn finish(&mut self, policy: &ScorePolicy) {
+        let seconds_left = (policy.par_seconds - self.elapsed_seconds).max(0.0);
+        self.breakdown.time_bonus = seconds_left.floor() as usize * policy.time_bonus_per_second;
+        self.breakdown.no_life_lost_bonus = if self.lives_lost == 0 {
+            policy.no_life_lost_bonus
+        } else {
+            0
+        };
+    }
 }
 
-pub fn update_scoreboard(scoreboard: Res<Scoreboard>, mut query: Query<&mut Text>) {
+/// Final score of the last completed level, shown on the level summary
+#[derive(Resource, Debug, Clone)]
+pub struct LevelResult(pub Scoreboard);
+
+#[derive(Component)]
+pub struct ScoreboardText;
+
+pub fn update_scoreboard(
+    scoreboard: Res<Scoreboard>,
+    mut query: Query<&mut Text, With<ScoreboardText>>,
+) {
     let mut text = query.single_mut();
-    text.sections[1].value = scoreboard.score.to_string();
+    text.sections[1].value = scoreboard.score().to_string();
+    text.sections[2].value = if scoreboard.multiplier > 1.0 {
+        format!(" x{:.1}", scoreboard.multiplier)
+    } else {
+        String::new()
+    };
 }
 
 #[derive(Component)]
 pub struct ScoreOnCollision(pub usize);
 
-pub fn handle_score_on_collision_events(
+pub fn handle_score_brick_destroyed_events(
     mut scoreboard: ResMut<Scoreboard>,
-    mut ev_collision: EventReader<CollisionEvent>,
-    collision_query: Query<(Entity, &ScoreOnCollision)>,
+    mut ev_brick_destroyed: EventReader<BrickDestroyed>,
+    mut ev_effect: EventWriter<EffectEvent>,
+    level: Res<Level>,
 ) {
-    let mut scored = HashSet::<Entity>::new();
-    for collision in ev_collision.read() {
-        for entity in collision.collidees {
-            if scored.contains(&entity) {
-                continue;
+    for brick in ev_brick_destroyed.read() {
+        let points = match brick.cause {
+            BrickDestroyCause::Hit { .. } => scoreboard.add_brick(brick.points, &level.score),
+            BrickDestroyCause::Explosion { chain_length } => {
+                scoreboard.add_chain(brick.points, chain_length)
             }
-            if let Ok((_, score)) = collision_query.get(entity) {
-                scoreboard.score += score.0;
-                scored.insert(entity);
-            }
-        }
+        };
+        ev_effect.send(EffectEvent::ScorePopup {
+            position: brick.position,
+            points,
+        });
     }
 }
 
+/// Ends the combo when a ball touches the paddle or is lost
+pub fn handle_combo_events(
+    mut scoreboard: ResMut<Scoreboard>,
+    mut ev_paddle_hit: EventReader<PaddleHit>,
+    mut ev_ball_lost: EventReader<BallLost>,
+) {
+    for _ in ev_paddle_hit.read() {
+        scoreboard.touch_paddle();
+    }
+    for _ in ev_ball_lost.read() {
+        scoreboard.lose_life();
+    }
+}
+
+pub fn update_score_multiplier(
+    mut scoreboard: ResMut<Scoreboard>,
+    level: Res<Level>,
+    time: GameTime,
+) {
+    scoreboard.decay_multiplier(time.delta_seconds(), &level.score);
+}
+
+pub fn update_level_clock(mut scoreboard: ResMut<Scoreboard>, time: Res<Time<Real>>) {
+    scoreboard.tick_clock(time.delta_seconds());
+}
+
 pub struct ScoreboardFactory;
 
 impl ScoreboardFactory {
     pub fn spawn(&self, parent: &mut ChildBuilder) {
-        parent.spawn(
+        parent.spawn((
             TextBundle::from_sections([
                 TextSection::new(
                     "Score: ",
@@ -59,6 +237,11 @@ impl ScoreboardFactory {
                     color: SCORE_COLOR,
                     ..default()
                 }),
+                TextSection::from_style(TextStyle {
+                    font_size: SCOREBOARD_FONT_SIZE,
+                    color: MULTIPLIER_COLOR,
+                    ..default()
+                }),
             ])
             .with_style(Style {
                 position_type: PositionType::Absolute,
@@ -66,6 +249,116 @@ impl ScoreboardFactory {
                 left: SCOREBOARD_TEXT_PADDING,
                 ..default()
             }),
+            ScoreboardText,
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rstest::rstest;
+
+    #[test]
+    fn test_combo_raises_multiplier() {
+        let policy = ScorePolicy {
+            multiplier_per_brick: 0.5,
+            max_multiplier: 2.0,
+            ..default()
+        };
+        let mut scoreboard = Scoreboard::default();
+
+        for _ in 0..4 {
+            scoreboard.add_brick(10, &policy);
+        }
+
+        // 10 + 15 + 20 + 20, the multiplier is capped at 2
+        assert_eq!(scoreboard.breakdown.bricks, 40);
+        assert_eq!(scoreboard.breakdown.combo_bonus, 25);
+        assert_eq!(scoreboard.longest_combo, 4);
+
+        scoreboard.touch_paddle();
+        scoreboard.add_brick(10, &policy);
+        assert_eq!(scoreboard.combo, 1);
+        assert_eq!(scoreboard.longest_combo, 4);
+    }
+
+    #[rstest]
+    #[case(false, 25)]
+    #[case(true, 20)]
+    fn test_touching_paddle_resets_multiplier(
+        #[case] touch_paddle: bool,
+        #[case] expected_score: usize,
+    ) {
+        let policy = ScorePolicy {
+            multiplier_per_brick: 0.5,
+            ..default()
+        };
+        let mut scoreboard = Scoreboard::default();
+
+        scoreboard.add_brick(10, &policy);
+        if touch_paddle {
+            scoreboard.touch_paddle();
+        }
+        scoreboard.add_brick(10, &policy);
+
+        assert_eq!(scoreboard.score(), expected_score);
+    }
+
+    #[test]
+    fn test_multiplier_decays() {
+        let policy = ScorePolicy {
+            multiplier_decay_per_second: 0.5,
+            ..default()
+        };
+        let mut scoreboard = Scoreboard {
+            multiplier: 2.0,
+            ..default()
+        };
+
+        scoreboard.decay_multiplier(1.0, &policy);
+        assert_eq!(scoreboard.multiplier, 1.5);
+        scoreboard.decay_multiplier(10.0, &policy);
+        assert_eq!(scoreboard.multiplier, 1.0);
+        assert_eq!(scoreboard.elapsed_seconds, 0.0);
+    }
+
+    #[test]
+    fn test_clock_ignores_multiplier() {
+        let mut scoreboard = Scoreboard {
+            multiplier: 2.0,
+            ..default()
+        };
+
+        scoreboard.tick_clock(1.5);
+        scoreboard.tick_clock(1.0);
+        assert_eq!(scoreboard.elapsed_seconds, 2.5);
+        assert_eq!(scoreboard.multiplier, 2.0);
+    }
+
+    #[rstest]
+    #[case(60.0, 0, 1200, 1000)]
+    #[case(60.5, 1, 1190, 0)]
+    #[case(300.0, 0, 0, 1000)]
+    fn test_level_completion_bonus(
+        #[case] elapsed_seconds: f32,
+        #[case] lives_lost: usize,
+        #[case] expected_time_bonus: usize,
+        #[case] expected_no_life_lost_bonus: usize,
+    ) {
+        let mut scoreboard = Scoreboard {
+            elapsed_seconds,
+            lives_lost,
+            ..default()
+        };
+
+        scoreboard.finish(&ScorePolicy::default());
+
+        assert_eq!(scoreboard.breakdown.time_bonus, expected_time_bonus);
+        assert_eq!(
+            scoreboard.breakdown.no_life_lost_bonus,
+            expected_no_life_lost_bonus
         );
     }
 }
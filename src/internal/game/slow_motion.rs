@@ -0,0 +1,120 @@
+use bevy::prelude::*;
+
+use crate::internal::physics::{GameTime, LocalTimeScale, TimeScale};
+
+use super::{
+    ball::Ball, explosive::Explosion, gameplay_events::PowerUpCollected, level::Level,
+    power_up::PowerUpKind,
+};
+
+#[derive(Debug, Clone, Copy)]
+pub struct SlowBallPolicy {
+    /// Time scale of the balls while the effect lasts
+    pub time_scale: f32,
+    /// Seconds the balls stay slow, refreshed by every collected slow ball power-up
+    pub duration_seconds: f32,
+}
+
+impl Default for SlowBallPolicy {
+    fn default() -> Self {
+        Self {
+            time_scale: 0.6,
+            duration_seconds: 10.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct BulletTimePolicy {
+    /// Global time scale while bullet time lasts
+    pub time_scale: f32,
+    /// Real seconds bullet time lasts, refreshed while the chain reaction goes on
+    pub duration_seconds: f32,
+    /// Length of the explosion chain that starts bullet time
+    pub min_chain_length: usize,
+}
+
+impl Default for BulletTimePolicy {
+    fn default() -> Self {
+        Self {
+            time_scale: 0.35,
+            duration_seconds: 0.8,
+            min_chain_length: 3,
+        }
+    }
+}
+
+/// Seconds the ball stays slowed down by `LocalTimeScale`
+#[derive(Component, Debug)]
+pub struct SlowBall {
+    pub remaining_seconds: f32,
+}
+
+/// Real seconds left of bullet time
+#[derive(Resource, Debug, Default)]
+pub struct BulletTime(pub f32);
+
+pub fn handle_slow_ball_power_ups(
+    mut commands: Commands,
+    mut ev_power_up: EventReader<PowerUpCollected>,
+    ball_query: Query<Entity, With<Ball>>,
+    level: Res<Level>,
+) {
+    let policy = &level.slow_ball;
+    for power_up in ev_power_up.read() {
+        if power_up.kind != PowerUpKind::SlowBall {
+            continue;
+        }
+        for entity in &ball_query {
+            commands.entity(entity).insert((
+                SlowBall {
+                    remaining_seconds: policy.duration_seconds,
+                },
+                LocalTimeScale(policy.time_scale),
+            ));
+        }
+    }
+}
+
+pub fn update_slow_balls(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut SlowBall)>,
+    time: GameTime,
+) {
+    for (entity, mut slow_ball) in &mut query {
+        slow_ball.remaining_seconds -= time.delta_seconds();
+        if slow_ball.remaining_seconds <= 0.0 {
+            commands
+                .entity(entity)
+                .remove::<(SlowBall, LocalTimeScale)>();
+        }
+    }
+}
+
+/// Starts bullet time on long chain reactions and drives the global `TimeScale`
+pub fn update_bullet_time(
+    mut bullet_time: ResMut<BulletTime>,
+    mut time_scale: ResMut<TimeScale>,
+    explosion_query: Query<&Explosion>,
+    level: Res<Level>,
+    time: GameTime,
+) {
+    let policy = &level.bullet_time;
+    if explosion_query
+        .iter()
+        .any(|explosion| explosion.chain_length() >= policy.min_chain_length)
+    {
+        bullet_time.0 = policy.duration_seconds;
+    }
+
+    // Bullet time runs on real time, otherwise it would stretch itself
+    bullet_time.0 = (bullet_time.0 - time.unscaled_delta_seconds()).max(0.0);
+    let target = if bullet_time.0 > 0.0 {
+        policy.time_scale
+    } else {
+        1.0
+    };
+    if time_scale.0 != target {
+        time_scale.0 = target;
+    }
+}
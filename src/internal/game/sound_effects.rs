@@ -0,0 +1,184 @@
+use bevy::prelude::*;
+
+use crate::internal::sound::sound::{PlaySound, SoundKind};
+
+use super::{
+    ball_speed::BallSpeedPolicy,
+    gameplay_events::{
+        BallLost, BrickDestroyCause, BrickDestroyed, BrickHit, Detonation, PaddleHit,
+        PowerUpCollected, WallHit,
+    },
+    level::Level,
+};
+
+const MIN_PITCH: f32 = 0.8;
+const MAX_PITCH: f32 = 1.6;
+const EXPLOSION_PITCH: f32 = 0.6;
+
+/// Faster balls sound higher, a ball at the starting speed plays at the sound's own pitch
+pub fn ball_speed_pitch(speed: f32, policy: &BallSpeedPolicy) -> f32 {
+    (speed / policy.starting_speed)
+        .sqrt()
+        .clamp(MIN_PITCH, MAX_PITCH)
+}
+
+pub fn handle_ball_sound_events(
+    mut ev_paddle_hit: EventReader<PaddleHit>,
+    mut ev_wall_hit: EventReader<WallHit>,
+    mut ev_ball_lost: EventReader<BallLost>,
+    mut ev_sound: EventWriter<PlaySound>,
+    level: Res<Level>,
+) {
+    let pitch = |velocity: Vec2| ball_speed_pitch(velocity.length(), &level.ball_speed);
+    for paddle_hit in ev_paddle_hit.read() {
+        ev_sound.send(PlaySound {
+            kind: SoundKind::Paddle,
+            speed: pitch(paddle_hit.velocity),
+        });
+    }
+    for wall_hit in ev_wall_hit.read() {
+        ev_sound.send(PlaySound {
+            kind: SoundKind::Wall,
+            speed: pitch(wall_hit.velocity),
+        });
+    }
+    for _ in ev_ball_lost.read() {
+        ev_sound.send(SoundKind::BallLost.into());
+    }
+}
+
+pub fn handle_brick_sound_events(
+    mut ev_brick_hit: EventReader<BrickHit>,
+    mut ev_brick_destroyed: EventReader<BrickDestroyed>,
+    mut ev_sound: EventWriter<PlaySound>,
+    level: Res<Level>,
+) {
+    let pitch = |velocity: Vec2| ball_speed_pitch(velocity.length(), &level.ball_speed);
+    for brick_hit in ev_brick_hit.read() {
+        ev_sound.send(PlaySound {
+            kind: SoundKind::BrickHit,
+            speed: pitch(brick_hit.velocity),
+        });
+    }
+    for brick in ev_brick_destroyed.read() {
+        // Bricks caught in explosions are heard through the detonation
+        if let BrickDestroyCause::Hit { velocity, .. } = brick.cause {
+            ev_sound.send(PlaySound {
+                kind: SoundKind::BrickBreak,
+                speed: pitch(velocity),
+            });
+        }
+    }
+}
+
+pub fn handle_power_up_sound_events(
+    mut ev_power_up: EventReader<PowerUpCollected>,
+    mut ev_sound: EventWriter<PlaySound>,
+) {
+    for _ in ev_power_up.read() {
+        ev_sound.send(SoundKind::PowerUp.into());
+    }
+}
+
+pub fn handle_explosion_sounds(
+    mut ev_detonation: EventReader<Detonation>,
+    mut ev_sound: EventWriter<PlaySound>,
+) {
+    for _ in ev_detonation.read() {
+        ev_sound.send(PlaySound {
+            kind: SoundKind::BrickBreak,
+            speed: EXPLOSION_PITCH,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rstest::rstest;
+
+    use crate::internal::{
+        game::{
+            ball::Ball,
+            brick::BrickBundle,
+            gameplay_events::{emit_gameplay_events, GameplayEventsPlugin},
+            wall::{EdgeWallLocation, WallBundle},
+        },
+        physics::{
+            CollisionBody, CollisionEvent, CollisionGroup, CollisionMask, CollisionProperties,
+            CollisionResult, Velocity,
+        },
+    };
+
+    #[rstest]
+    #[case(200.0, 1.0)]
+    #[case(450.0, 1.5)]
+    #[case(50.0, MIN_PITCH)]
+    #[case(2000.0, MAX_PITCH)]
+    fn test_ball_speed_pitch(#[case] speed: f32, #[case] expected: f32) {
+        let pitch = ball_speed_pitch(speed, &BallSpeedPolicy::default());
+
+        assert!((pitch - expected).abs() < 1e-3, "{pitch}");
+    }
+
+    #[rstest]
+    #[case::brick(true, &[SoundKind::BrickHit, SoundKind::BrickBreak])]
+    #[case::wall(false, &[SoundKind::Wall])]
+    fn test_ball_collision_sounds(#[case] hit_brick: bool, #[case] expected: &[SoundKind]) {
+        let mut app = App::new();
+        app.add_plugins(GameplayEventsPlugin)
+            .init_resource::<Level>()
+            .add_event::<CollisionEvent>()
+            .add_event::<PlaySound>()
+            .add_systems(
+                Update,
+                (
+                    emit_gameplay_events,
+                    handle_ball_sound_events,
+                    handle_brick_sound_events,
+                )
+                    .chain(),
+            );
+        let ball = app
+            .world
+            .spawn((
+                Ball,
+                Transform::default(),
+                CollisionProperties {
+                    body: CollisionBody::Circle,
+                    group: CollisionMask::new(&[CollisionGroup::Ball]),
+                    ..default()
+                },
+                Velocity(Vec2::new(0.0, 200.0)),
+            ))
+            .id();
+        let other = if hit_brick {
+            app.world
+                .spawn(BrickBundle::new(Vec2::ZERO, Vec2::splat(10.0), Color::RED))
+                .id()
+        } else {
+            app.world
+                .spawn(WallBundle::from(EdgeWallLocation::Top))
+                .id()
+        };
+        app.world.send_event(CollisionEvent {
+            collidees: [ball, other],
+            collision_result: CollisionResult {
+                collision_normal: Vec2::Y,
+                corrigation_vector: Vec2::ZERO,
+            },
+            pass_through: false,
+        });
+
+        app.update();
+
+        let sounds = app.world.resource::<Events<PlaySound>>();
+        let kinds: Vec<_> = sounds
+            .get_reader()
+            .read(sounds)
+            .map(|sound| sound.kind)
+            .collect();
+        assert_eq!(kinds, expected);
+    }
+}
@@ -72,7 +72,11 @@ impl From<EdgeWallLocation> for WallBundle {
             collision_properties: CollisionProperties {
                 body: CollisionBody::Rect,
                 group: CollisionMask::new(&[CollisionGroup::Wall]),
-                mask: CollisionMask::new(&[CollisionGroup::Ball, CollisionGroup::Powerup]),
+                mask: CollisionMask::new(&[
+                    CollisionGroup::Ball,
+                    CollisionGroup::Powerup,
+                    CollisionGroup::Laser,
+                ]),
                 ..default()
             },
         }
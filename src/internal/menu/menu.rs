@@ -1,19 +1,46 @@
 use bevy::prelude::*;
 
-use crate::internal::states::AppState;
+use crate::internal::{
+    camera::CameraEffectsSettings, effects::effects::TrailSettings, sound::sound::AudioSettings,
+    states::AppState,
+};
+
+const VOLUME_STEP: f32 = 0.1;
 
 pub struct MenuPlugin;
 
 #[derive(Component)]
 pub struct MenuState;
 
+#[derive(Component)]
+pub struct ShakeText;
+
+#[derive(Component)]
+pub struct HitStopText;
+
+#[derive(Component)]
+pub struct TrailText;
+
+#[derive(Component)]
+pub struct AudioText;
+
 impl Plugin for MenuPlugin {
     fn build(&self, app: &mut App) {
         app.add_systems(OnEnter(AppState::MainMenu), menu_setup)
             .add_systems(OnExit(AppState::MainMenu), menu_teardown)
             .add_systems(
                 Update,
-                (bevy::window::close_on_esc, space_to_start).run_if(in_state(AppState::MainMenu)),
+                (
+                    bevy::window::close_on_esc,
+                    space_to_start,
+                    toggle_camera_effects,
+                    update_camera_effects_text,
+                    toggle_trails,
+                    update_trails_text,
+                    change_audio,
+                    update_audio_text,
+                )
+                    .run_if(in_state(AppState::MainMenu)),
             );
     }
 }
@@ -30,6 +57,7 @@ fn menu_setup(mut commands: Commands) {
         style: Style {
             width: Val::Percent(100.),
             height: Val::Percent(100.),
+            flex_direction: FlexDirection::Column,
             ..Default::default()
         },
         ..Default::default() 
@@ -44,6 +72,50 @@ fn menu_setup(mut commands: Commands) {
                 },
             )]),
         );
+        parent.spawn((
+            TextBundle::from_section(
+                "",
+                TextStyle {
+                    font_size: 30.,
+                    color: Color::DARK_GRAY,
+                    ..default()
+                },
+            ),
+            ShakeText,
+        ));
+        parent.spawn((
+            TextBundle::from_section(
+                "",
+                TextStyle {
+                    font_size: 30.,
+                    color: Color::DARK_GRAY,
+                    ..default()
+                },
+            ),
+            HitStopText,
+        ));
+        parent.spawn((
+            TextBundle::from_section(
+                "",
+                TextStyle {
+                    font_size: 30.,
+                    color: Color::DARK_GRAY,
+                    ..default()
+                },
+            ),
+            TrailText,
+        ));
+        parent.spawn((
+            TextBundle::from_section(
+                "",
+                TextStyle {
+                    font_size: 30.,
+                    color: Color::DARK_GRAY,
+                    ..default()
+                },
+            ),
+            AudioText,
+        ));
     });
 }
 
@@ -52,3 +124,65 @@ pub fn space_to_start(mut next_state: ResMut<NextState<AppState>>, input: Res<In
         next_state.set(AppState::Game);
     }
 }
+
+pub fn toggle_camera_effects(
+    mut settings: ResMut<CameraEffectsSettings>,
+    input: Res<Input<KeyCode>>,
+) {
+    if input.just_pressed(KeyCode::S) {
+        settings.shake = !settings.shake;
+    }
+    if input.just_pressed(KeyCode::H) {
+        settings.hit_stop = !settings.hit_stop;
+    }
+}
+
+fn update_camera_effects_text(
+    settings: Res<CameraEffectsSettings>,
+    mut shake_query: Query<&mut Text, (With<ShakeText>, Without<HitStopText>)>,
+    mut hit_stop_query: Query<&mut Text, With<HitStopText>>,
+) {
+    let state = |enabled| if enabled { "On" } else { "Off" };
+    for mut text in &mut shake_query {
+        text.sections[0].value = format!("Press S to toggle screen shake: {}", state(settings.shake));
+    }
+    for mut text in &mut hit_stop_query {
+        text.sections[0].value = format!("Press H to toggle hit-stop: {}", state(settings.hit_stop));
+    }
+}
+
+pub fn toggle_trails(mut settings: ResMut<TrailSettings>, input: Res<Input<KeyCode>>) {
+    if input.just_pressed(KeyCode::T) {
+        settings.enabled = !settings.enabled;
+    }
+}
+
+fn update_trails_text(settings: Res<TrailSettings>, mut query: Query<&mut Text, With<TrailText>>) {
+    let state = if settings.enabled { "On" } else { "Off" };
+    for mut text in &mut query {
+        text.sections[0].value = format!("Press T to toggle ball trails: {state}");
+    }
+}
+
+pub fn change_audio(mut settings: ResMut<AudioSettings>, input: Res<Input<KeyCode>>) {
+    if input.just_pressed(KeyCode::M) {
+        settings.muted = !settings.muted;
+    }
+    if input.just_pressed(KeyCode::Minus) {
+        settings.volume = (settings.volume - VOLUME_STEP).max(0.0);
+    }
+    if input.just_pressed(KeyCode::Equals) {
+        settings.volume = (settings.volume + VOLUME_STEP).min(1.0);
+    }
+}
+
+fn update_audio_text(settings: Res<AudioSettings>, mut query: Query<&mut Text, With<AudioText>>) {
+    let state = if settings.muted {
+        "Muted".to_string()
+    } else {
+        format!("{:.0}%", settings.volume * 100.0)
+    };
+    for mut text in &mut query {
+        text.sections[0].value = format!("Press M to mute, - and = to change the volume: {state}");
+    }
+}
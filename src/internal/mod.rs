@@ -1,5 +1,8 @@
 pub mod states;
 pub mod camera;
+pub mod effects;
 pub mod game;
 pub mod menu;
-pub mod physics;
\ No newline at end of file
+pub mod physics;
+pub mod sound;
+pub mod summary;
\ No newline at end of file
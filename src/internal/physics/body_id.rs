@@ -0,0 +1,54 @@
+use bevy::prelude::*;
+
+use super::{CollisionEvent, CollisionProperties, ForceField};
+
+/**
+ * Persistent, monotonically increasing id of a collision body or force field.
+ * Used to order collision pairs and force fields when `PhysicsConfig::deterministic` is set.
+ */
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BodyId(pub u64);
+
+#[derive(Resource, Default)]
+pub struct BodyIdCounter(u64);
+
+pub fn assign_body_ids(
+    mut commands: Commands,
+    mut counter: ResMut<BodyIdCounter>,
+    query: Query<
+        Entity,
+        (
+            Or<(With<CollisionProperties>, With<ForceField>)>,
+            Without<BodyId>,
+        ),
+    >,
+) {
+    // Sort by entity so ids do not depend on archetype iteration order
+    let mut entities: Vec<Entity> = query.iter().collect();
+    entities.sort();
+    for entity in entities {
+        commands.entity(entity).insert(BodyId(counter.0));
+        counter.0 += 1;
+    }
+}
+
+pub fn body_sort_key(body_id: Option<&BodyId>, entity: Entity) -> (u64, Entity) {
+    (body_id.map_or(u64::MAX, |id| id.0), entity)
+}
+
+/// Reads collision events, sorted by body ids when `deterministic` is set
+pub fn read_ordered_collisions<'a>(
+    ev_collision: &'a mut EventReader<CollisionEvent>,
+    body_id_query: &Query<&BodyId>,
+    deterministic: bool,
+) -> Vec<&'a CollisionEvent> {
+    let mut collisions: Vec<&CollisionEvent> = ev_collision.read().collect();
+    if deterministic {
+        collisions.sort_by_key(|collision| {
+            collision
+                .collidees
+                .map(|entity| body_sort_key(body_id_query.get(entity).ok(), entity))
+        });
+    }
+    collisions
+}
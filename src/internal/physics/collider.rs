@@ -1,15 +1,20 @@
-use bevy::prelude::*;
+use bevy::{ecs::query::WorldQuery, prelude::*};
 
 use super::{
+    body_sort_key,
     collision::{circle_circle_collision, rect_circle_collision, rect_rect_collision},
+    contact_friction, inverse_mass, passes_through, read_ordered_collisions,
     velocity::Velocity,
-    CollisionBody, CollisionProperties,
+    AngularVelocity, BodyId, CollisionBody, CollisionProperties, Mass, MomentumTransfer,
+    PassThrough, PhysicsConfig, RigidBody,
 };
 
 #[derive(Event, Debug)]
 pub struct CollisionEvent {
     pub collidees: [Entity; 2],
     pub collision_result: CollisionResult,
+    /// One of the bodies passes through the other, physics doesn't respond to the collision
+    pub pass_through: bool,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -18,135 +23,282 @@ pub struct CollisionResult {
     pub corrigation_vector: Vec2,
 }
 
+type CollisionBodyItem<'a> = (
+    Entity,
+    &'a Transform,
+    &'a CollisionProperties,
+    Option<&'a PassThrough>,
+);
+
 pub fn check_for_collisions(
     mut ev_collision: EventWriter<CollisionEvent>,
-    collision_query: Query<(Entity, &Transform, &CollisionProperties)>,
+    collision_query: Query<(CollisionBodyItem, Option<&BodyId>)>,
+    config: Res<PhysicsConfig>,
 ) {
-    for [(a_entity, a_transform, a_collision_properties), (b_entity, b_transform, b_collision_properties)] in
-        collision_query.iter_combinations()
-    {
-        if !a_collision_properties
-            .mask
-            .contains(&b_collision_properties.group)
-            || !b_collision_properties
-                .mask
-                .contains(&a_collision_properties.group)
-        {
-            continue;
+    if config.deterministic {
+        let mut bodies: Vec<_> = collision_query.iter().collect();
+        bodies.sort_by_key(|((entity, ..), body_id)| body_sort_key(*body_id, *entity));
+        for (index, (a, _)) in bodies.iter().enumerate() {
+            for (b, _) in &bodies[index + 1..] {
+                if let Some(collision) = check_collision_pair(*a, *b) {
+                    ev_collision.send(collision);
+                }
+            }
         }
+    } else {
+        for [(a, _), (b, _)] in collision_query.iter_combinations() {
+            if let Some(collision) = check_collision_pair(a, b) {
+                ev_collision.send(collision);
+            }
+        }
+    }
+}
 
-        let (
+fn check_collision_pair(
+    (a_entity, a_transform, a_collision_properties, a_pass_through): CollisionBodyItem,
+    (b_entity, b_transform, b_collision_properties, b_pass_through): CollisionBodyItem,
+) -> Option<CollisionEvent> {
+    if !a_collision_properties
+        .mask
+        .contains(&b_collision_properties.group)
+        || !b_collision_properties
+            .mask
+            .contains(&a_collision_properties.group)
+    {
+        return None;
+    }
+    let pass_through = passes_through(
+        (a_pass_through, a_collision_properties),
+        (b_pass_through, b_collision_properties),
+    );
+
+    let (
+        (a_entity, a_transform, a_collision_properties),
+        (b_entity, b_transform, b_collision_properties),
+    ) = match (a_collision_properties.body, b_collision_properties.body) {
+        (CollisionBody::Circle, CollisionBody::Rect) => (
+            (b_entity, b_transform, b_collision_properties),
+            (a_entity, a_transform, a_collision_properties),
+        ),
+
+        (CollisionBody::Rect, CollisionBody::Rect)
+        | (CollisionBody::Rect, CollisionBody::Circle)
+        | (CollisionBody::Circle, CollisionBody::Circle) => (
             (a_entity, a_transform, a_collision_properties),
             (b_entity, b_transform, b_collision_properties),
-        ) = match (a_collision_properties.body, b_collision_properties.body) {
-            (CollisionBody::Circle, CollisionBody::Rect) => (
-                (b_entity, b_transform, b_collision_properties),
-                (a_entity, a_transform, a_collision_properties),
-            ),
+        ),
+    };
 
-            (CollisionBody::Rect, CollisionBody::Rect)
-            | (CollisionBody::Rect, CollisionBody::Circle)
-            | (CollisionBody::Circle, CollisionBody::Circle) => (
-                (a_entity, a_transform, a_collision_properties),
-                (b_entity, b_transform, b_collision_properties),
+    let collision_result: Option<CollisionResult> =
+        match (a_collision_properties.body, b_collision_properties.body) {
+            (CollisionBody::Rect, CollisionBody::Rect) => rect_rect_collision(
+                a_transform.translation.truncate(),
+                a_transform.scale.truncate(),
+                b_transform.translation.truncate(),
+                b_transform.scale.truncate(),
             ),
+            (CollisionBody::Circle, CollisionBody::Circle) => circle_circle_collision(
+                a_transform.translation.truncate(),
+                a_transform.scale.truncate(),
+                b_transform.translation.truncate(),
+                b_transform.scale.truncate(),
+            ),
+            (CollisionBody::Rect, CollisionBody::Circle) => rect_circle_collision(
+                a_transform.translation.truncate(),
+                a_transform.scale.truncate(),
+                b_transform.translation.truncate(),
+                b_transform.scale.truncate(),
+            ),
+            _ => panic!("Invalid collision body combination"),
         };
 
-        let collision_result: Option<CollisionResult> =
-            match (a_collision_properties.body, b_collision_properties.body) {
-                (CollisionBody::Rect, CollisionBody::Rect) => rect_rect_collision(
-                    a_transform.translation.truncate(),
-                    a_transform.scale.truncate(),
-                    b_transform.translation.truncate(),
-                    b_transform.scale.truncate(),
-                ),
-                (CollisionBody::Circle, CollisionBody::Circle) => circle_circle_collision(
-                    a_transform.translation.truncate(),
-                    a_transform.scale.truncate(),
-                    b_transform.translation.truncate(),
-                    b_transform.scale.truncate(),
-                ),
-                (CollisionBody::Rect, CollisionBody::Circle) => rect_circle_collision(
-                    a_transform.translation.truncate(),
-                    a_transform.scale.truncate(),
-                    b_transform.translation.truncate(),
-                    b_transform.scale.truncate(),
-                ),
-                _ => panic!("Invalid collision body combination"),
-            };
-
-        if let Some(collision_result) = collision_result {
-            ev_collision.send(CollisionEvent {
-                collidees: [a_entity, b_entity],
-                collision_result,
-            });
-        }
-    }
+    collision_result.map(|collision_result| CollisionEvent {
+        collidees: [a_entity, b_entity],
+        collision_result,
+        pass_through,
+    })
 }
 
 pub fn handle_correction(
     mut ev_collision: EventReader<CollisionEvent>,
-    mut collision_query: Query<(&mut Transform, Option<&Velocity>)>,
+    mut collision_query: Query<(&mut Transform, Option<&RigidBody>, Option<&Mass>)>,
+    body_id_query: Query<&BodyId>,
+    config: Res<PhysicsConfig>,
 ) {
-    for collision in ev_collision.read() {
+    for collision in
+        read_ordered_collisions(&mut ev_collision, &body_id_query, config.deterministic)
+    {
+        if collision.pass_through {
+            continue;
+        }
         let entries = collision_query.get_many_mut(collision.collidees);
         if let Ok(mut entries) = entries {
-            let corrigation_vectors = match (entries[0].1.is_some(), entries[1].1.is_some()) {
-                (true, true) => [
-                    collision.collision_result.corrigation_vector / 2.,
-                    collision.collision_result.corrigation_vector / -2.,
-                ],
-                (true, false) => [collision.collision_result.corrigation_vector, Vec2::ZERO],
-                (false, true) => [Vec2::ZERO, collision.collision_result.corrigation_vector],
-                (false, false) => [Vec2::ZERO, Vec2::ZERO],
-            };
-            entries[0].0.translation += corrigation_vectors[0].extend(0.0);
-            entries[1].0.translation += corrigation_vectors[1].extend(0.0);
+            let inverse_masses = [
+                inverse_mass(entries[0].1, entries[0].2),
+                inverse_mass(entries[1].1, entries[1].2),
+            ];
+            let total_inverse_mass = inverse_masses[0] + inverse_masses[1];
+            if total_inverse_mass == 0.0 {
+                continue;
+            }
+
+            // The normal points from the first collidee towards the second one
+            let corrigation_vector = collision.collision_result.corrigation_vector;
+            entries[0].0.translation -=
+                (corrigation_vector * inverse_masses[0] / total_inverse_mass).extend(0.0);
+            entries[1].0.translation +=
+                (corrigation_vector * inverse_masses[1] / total_inverse_mass).extend(0.0);
         }
     }
 }
 
+#[derive(WorldQuery)]
+#[world_query(mutable)]
+pub struct BounceQuery {
+    velocity: Option<&'static mut Velocity>,
+    angular_velocity: Option<&'static mut AngularVelocity>,
+    rigid_body: Option<&'static RigidBody>,
+    mass: Option<&'static Mass>,
+    momentum_transfer: Option<&'static MomentumTransfer>,
+    transform: &'static Transform,
+    collision_properties: &'static CollisionProperties,
+}
+
+impl BounceQueryItem<'_> {
+    fn rigid_body(&self) -> RigidBody {
+        self.rigid_body.copied().unwrap_or_default()
+    }
+
+    fn velocity(&self) -> Vec2 {
+        self.velocity
+            .as_ref()
+            .map_or(Vec2::ZERO, |velocity| velocity.0)
+    }
+}
+
 pub fn handle_collision_bounce(
     mut ev_collision: EventReader<CollisionEvent>,
-    mut collidee_query: Query<(Option<&mut Velocity>, &CollisionProperties)>,
+    mut collidee_query: Query<BounceQuery>,
+    body_id_query: Query<&BodyId>,
+    config: Res<PhysicsConfig>,
 ) {
-    for collision in ev_collision.read() {
-        let collidees = collidee_query.get_many_mut(collision.collidees);
-
-        match collidees {
-            Ok([(Some(mut velocity), moving_collision_props), (None, stat_collision_props)])
-            | Ok([(None, stat_collision_props), (Some(mut velocity), moving_collision_props)]) => {
-                let new_velocity = velocity.0 * -1.0;
-
-                let velocity_speed = new_velocity.length();
-                let velocity_speed = velocity_speed
-                    * moving_collision_props.bounciness
-                    * stat_collision_props.bounciness;
-
-                let velocity_angle = collision
-                    .collision_result
-                    .collision_normal
-                    .angle_between(new_velocity)
-                    * -1.;
-                const MAX_ANGLE: f32 = std::f32::consts::FRAC_PI_2 / 9. * 8.;
-                let velocity_angle = velocity_angle.clamp(-MAX_ANGLE, MAX_ANGLE);
-                let new_velocity = collision
-                    .collision_result
-                    .collision_normal
-                    .rotate(Vec2::from_angle(velocity_angle))
-                    * velocity_speed;
-
-                velocity.x = new_velocity.x;
-                velocity.y = new_velocity.y;
-            }
-            // TODO - moving moving collision
-            // Ok([Some(mut a_velocity), Some(mut b_velocity)]) => {
-            //     a_velocity.x *= -1.;
-            //     a_velocity.y *= -1.;
-            //     b_velocity.x *= -1.;
-            //     b_velocity.y *= -1.;
-            // }
-            _ => panic!("Invalid collision"),
+    for collision in
+        read_ordered_collisions(&mut ev_collision, &body_id_query, config.deterministic)
+    {
+        if collision.pass_through {
+            continue;
+        }
+        let Ok([a, b]) = collidee_query.get_many_mut(collision.collidees) else {
+            panic!("Invalid collision");
+        };
+        let normal = collision.collision_result.collision_normal;
+
+        match (a.rigid_body(), b.rigid_body()) {
+            (RigidBody::Dynamic, RigidBody::Dynamic) => bounce_dynamic_pair(a, b, normal),
+            (RigidBody::Dynamic, _) => bounce_off(a, b, -normal),
+            (_, RigidBody::Dynamic) => bounce_off(b, a, normal),
+            // Neither body responds to the collision
+            _ => {}
         }
     }
 }
+
+/// Bounces a dynamic body off a static or kinematic one, `normal` points towards the dynamic body
+fn bounce_off(moving: BounceQueryItem, stat: BounceQueryItem, normal: Vec2) {
+    let stat_velocity = stat.velocity();
+    let Some(mut velocity) = moving.velocity else {
+        return;
+    };
+
+    // Bounce in the frame of the other body, so a moving body pushes along its velocity
+    let mut new_velocity = reflect(velocity.0 - stat_velocity, normal)
+        * moving.collision_properties.bounciness
+        * stat.collision_properties.bounciness
+        + stat_velocity;
+
+    if let Some(momentum_transfer) = stat.momentum_transfer {
+        new_velocity += stat_velocity * momentum_transfer.0;
+    }
+
+    if let Some(mut angular_velocity) = moving.angular_velocity {
+        (new_velocity, angular_velocity.0) = contact_friction(
+            new_velocity,
+            angular_velocity.0,
+            moving.transform.scale.x / 2.0,
+            normal,
+            stat_velocity,
+            moving.collision_properties.friction * stat.collision_properties.friction,
+        );
+    }
+
+    velocity.x = new_velocity.x;
+    velocity.y = new_velocity.y;
+}
+
+/// Exchanges momentum along the normal between two dynamic bodies, `normal` points from `a` to `b`
+fn bounce_dynamic_pair(a: BounceQueryItem, b: BounceQueryItem, normal: Vec2) {
+    let approach_speed = (b.velocity() - a.velocity()).dot(normal);
+    if approach_speed >= 0.0 {
+        return;
+    }
+
+    let a_inverse_mass = inverse_mass(a.rigid_body, a.mass);
+    let b_inverse_mass = inverse_mass(b.rigid_body, b.mass);
+    let restitution = a.collision_properties.bounciness * b.collision_properties.bounciness;
+    let impulse = -(1.0 + restitution) * approach_speed / (a_inverse_mass + b_inverse_mass);
+
+    if let Some(mut velocity) = a.velocity {
+        velocity.0 -= normal * impulse * a_inverse_mass;
+    }
+    if let Some(mut velocity) = b.velocity {
+        velocity.0 += normal * impulse * b_inverse_mass;
+    }
+}
+
+// Cosine and sine of the steepest allowed bounce, 80 degrees off the normal
+const MAX_BOUNCE_COS: f32 = 0.173_648_18;
+const MAX_BOUNCE_SIN: f32 = 0.984_807_7;
+
+/**
+ * Mirrors `velocity` about a surface with the given unit `normal`, keeping its speed.
+ * Bounces leaving further than 80 degrees off the normal are clamped to 80 degrees.
+ * Uses vector arithmetic only, so results do not depend on trigonometry implementations.
+ */
+pub fn reflect(velocity: Vec2, normal: Vec2) -> Vec2 {
+    let reflected = velocity - 2.0 * velocity.dot(normal) * normal;
+    let speed = reflected.length();
+    let along_normal = reflected.dot(normal);
+    if along_normal >= MAX_BOUNCE_COS * speed {
+        return reflected;
+    }
+
+    let tangent = (reflected - along_normal * normal)
+        .try_normalize()
+        .unwrap_or(normal.perp());
+    (normal * MAX_BOUNCE_COS + tangent * MAX_BOUNCE_SIN) * speed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(Vec2::new(0.0, -100.0), Vec2::Y, Vec2::new(0.0, 100.0))]
+    #[case(Vec2::new(30.0, -40.0), Vec2::Y, Vec2::new(30.0, 40.0))]
+    #[case(Vec2::new(30.0, -40.0), Vec2::NEG_X, Vec2::new(-30.0, -40.0))]
+    #[case(
+        Vec2::new(-100.0, -1.0),
+        Vec2::Y,
+        Vec2::new(-MAX_BOUNCE_SIN, MAX_BOUNCE_COS) * Vec2::new(-100.0, -1.0).length()
+    )]
+    fn test_reflect(#[case] velocity: Vec2, #[case] normal: Vec2, #[case] expected: Vec2) {
+        let result = reflect(velocity, normal);
+
+        assert!(
+            (result - expected).length() < 1e-3,
+            "{result} != {expected}"
+        );
+    }
+}
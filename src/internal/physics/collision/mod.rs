@@ -1,7 +1,9 @@
 mod circle_circle;
 mod rect_rect;
 mod rect_circle;
+mod ray;
 
 pub(super) use circle_circle::*;
 pub(super) use rect_rect::*;
-pub(super) use rect_circle::*;
\ No newline at end of file
+pub(super) use rect_circle::*;
+pub(super) use ray::*;
\ No newline at end of file
@@ -0,0 +1,265 @@
+use bevy::prelude::*;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RayIntersection {
+    pub distance: f32,
+    pub normal: Vec2,
+}
+
+/// `direction` must be normalized. A ray starting inside the rect hits at distance 0.
+pub fn ray_rect_intersection(
+    origin: Vec2,
+    direction: Vec2,
+    rect_center: Vec2,
+    rect_size: Vec2,
+) -> Option<RayIntersection> {
+    let rect_min = rect_center - rect_size / 2.0;
+    let rect_max = rect_center + rect_size / 2.0;
+
+    if origin.cmpge(rect_min).all() && origin.cmple(rect_max).all() {
+        return Some(RayIntersection {
+            distance: 0.0,
+            normal: -direction,
+        });
+    }
+
+    // slab method, tracking which axis produced the entry point
+    let mut t_enter = f32::NEG_INFINITY;
+    let mut t_exit = f32::INFINITY;
+    let mut normal = Vec2::ZERO;
+
+    for axis in 0..2 {
+        if direction[axis] == 0.0 {
+            if origin[axis] < rect_min[axis] || origin[axis] > rect_max[axis] {
+                return None;
+            }
+            continue;
+        }
+        let t_min = (rect_min[axis] - origin[axis]) / direction[axis];
+        let t_max = (rect_max[axis] - origin[axis]) / direction[axis];
+        let (t_near, t_far) = if t_min < t_max {
+            (t_min, t_max)
+        } else {
+            (t_max, t_min)
+        };
+        if t_near > t_enter {
+            t_enter = t_near;
+            normal = Vec2::ZERO;
+            normal[axis] = -direction[axis].signum();
+        }
+        t_exit = t_exit.min(t_far);
+    }
+
+    if t_enter > t_exit || t_exit < 0.0 {
+        return None;
+    }
+
+    Some(RayIntersection {
+        distance: t_enter,
+        normal,
+    })
+}
+
+/// `direction` must be normalized. A ray starting inside the circle hits at distance 0.
+pub fn ray_circle_intersection(
+    origin: Vec2,
+    direction: Vec2,
+    circle_center: Vec2,
+    circle_radius: f32,
+) -> Option<RayIntersection> {
+    let to_origin = origin - circle_center;
+    let c = to_origin.length_squared() - circle_radius * circle_radius;
+
+    if c <= 0.0 {
+        return Some(RayIntersection {
+            distance: 0.0,
+            normal: -direction,
+        });
+    }
+
+    let b = to_origin.dot(direction);
+    if b > 0.0 {
+        return None;
+    }
+
+    let discriminant = b * b - c;
+    if discriminant < 0.0 {
+        return None;
+    }
+
+    let distance = -b - discriminant.sqrt();
+    let normal = (origin + direction * distance - circle_center) / circle_radius;
+    Some(RayIntersection { distance, normal })
+}
+
+/// Sweeps a circle along a ray against a rect, treating the rect as its
+/// Minkowski sum with the circle (an expanded rect with rounded corners).
+pub fn circle_cast_rect_intersection(
+    origin: Vec2,
+    direction: Vec2,
+    circle_radius: f32,
+    rect_center: Vec2,
+    rect_size: Vec2,
+) -> Option<RayIntersection> {
+    let expanded = ray_rect_intersection(
+        origin,
+        direction,
+        rect_center,
+        rect_size + Vec2::splat(circle_radius * 2.0),
+    )?;
+
+    let rect_half_size = rect_size / 2.0;
+    let local_hit = origin + direction * expanded.distance - rect_center;
+    if local_hit.x.abs() > rect_half_size.x && local_hit.y.abs() > rect_half_size.y {
+        let corner = rect_center + rect_half_size * local_hit.signum();
+        ray_circle_intersection(origin, direction, corner, circle_radius)
+    } else {
+        Some(expanded)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(
+        Vec2::new(-20.0, 0.0),
+        Vec2::new(1.0, 0.0),
+        Vec2::new(0.0, 0.0),
+        Vec2::new(10.0, 10.0),
+        Some(RayIntersection {
+            distance: 15.0,
+            normal: Vec2::new(-1.0, 0.0),
+        })
+    )]
+    #[case(
+        Vec2::new(0.0, 20.0),
+        Vec2::new(0.0, -1.0),
+        Vec2::new(0.0, 0.0),
+        Vec2::new(10.0, 10.0),
+        Some(RayIntersection {
+            distance: 15.0,
+            normal: Vec2::new(0.0, 1.0),
+        })
+    )]
+    #[case(
+        Vec2::new(-20.0, 0.0),
+        Vec2::new(-1.0, 0.0),
+        Vec2::new(0.0, 0.0),
+        Vec2::new(10.0, 10.0),
+        None
+    )]
+    #[case(
+        Vec2::new(-20.0, 20.0),
+        Vec2::new(1.0, 0.0),
+        Vec2::new(0.0, 0.0),
+        Vec2::new(10.0, 10.0),
+        None
+    )]
+    #[case(
+        Vec2::new(1.0, 1.0),
+        Vec2::new(1.0, 0.0),
+        Vec2::new(0.0, 0.0),
+        Vec2::new(10.0, 10.0),
+        Some(RayIntersection {
+            distance: 0.0,
+            normal: Vec2::new(-1.0, 0.0),
+        })
+    )]
+    fn test_ray_rect_intersection(
+        #[case] origin: Vec2,
+        #[case] direction: Vec2,
+        #[case] rect_center: Vec2,
+        #[case] rect_size: Vec2,
+        #[case] expected: Option<RayIntersection>,
+    ) {
+        let intersection = ray_rect_intersection(origin, direction, rect_center, rect_size);
+
+        assert_eq!(intersection, expected);
+    }
+
+    #[rstest]
+    #[case(
+        Vec2::new(-20.0, 0.0),
+        Vec2::new(1.0, 0.0),
+        Vec2::new(0.0, 0.0),
+        5.0,
+        Some(RayIntersection {
+            distance: 15.0,
+            normal: Vec2::new(-1.0, 0.0),
+        })
+    )]
+    #[case(
+        Vec2::new(-20.0, 10.0),
+        Vec2::new(1.0, 0.0),
+        Vec2::new(0.0, 0.0),
+        5.0,
+        None
+    )]
+    #[case(
+        Vec2::new(20.0, 0.0),
+        Vec2::new(1.0, 0.0),
+        Vec2::new(0.0, 0.0),
+        5.0,
+        None
+    )]
+    fn test_ray_circle_intersection(
+        #[case] origin: Vec2,
+        #[case] direction: Vec2,
+        #[case] circle_center: Vec2,
+        #[case] circle_radius: f32,
+        #[case] expected: Option<RayIntersection>,
+    ) {
+        let intersection = ray_circle_intersection(origin, direction, circle_center, circle_radius);
+
+        assert_eq!(intersection, expected);
+    }
+
+    #[rstest]
+    #[case(
+        Vec2::new(-20.0, 0.0),
+        Vec2::new(1.0, 0.0),
+        2.0,
+        Vec2::new(0.0, 0.0),
+        Vec2::new(10.0, 10.0),
+        Some(RayIntersection {
+            distance: 13.0,
+            normal: Vec2::new(-1.0, 0.0),
+        })
+    )]
+    #[case(
+        Vec2::new(-20.0, 7.0),
+        Vec2::new(1.0, 0.0),
+        2.0,
+        Vec2::new(0.0, 0.0),
+        Vec2::new(10.0, 10.0),
+        Some(RayIntersection {
+            distance: 15.0,
+            normal: Vec2::new(0.0, 1.0),
+        })
+    )]
+    #[case(
+        Vec2::new(-20.0, -8.5),
+        Vec2::new(1.0, 1.0).normalize(),
+        1.0,
+        Vec2::new(0.0, 0.0),
+        Vec2::new(10.0, 10.0),
+        None
+    )]
+    fn test_circle_cast_rect_intersection(
+        #[case] origin: Vec2,
+        #[case] direction: Vec2,
+        #[case] circle_radius: f32,
+        #[case] rect_center: Vec2,
+        #[case] rect_size: Vec2,
+        #[case] expected: Option<RayIntersection>,
+    ) {
+        let intersection =
+            circle_cast_rect_intersection(origin, direction, circle_radius, rect_center, rect_size);
+
+        assert_eq!(intersection, expected);
+    }
+}
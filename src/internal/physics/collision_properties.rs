@@ -8,13 +8,15 @@ pub enum CollisionGroup {
     Block = 4,
     Wall = 8,
     Powerup = 16,
+    Laser = 32,
 }
-pub const COLLISION_GROUPS: [CollisionGroup; 5] = [
+pub const COLLISION_GROUPS: [CollisionGroup; 6] = [
     CollisionGroup::Paddle,
     CollisionGroup::Ball,
     CollisionGroup::Block,
     CollisionGroup::Wall,
     CollisionGroup::Powerup,
+    CollisionGroup::Laser,
 ];
 
 #[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
@@ -73,12 +75,14 @@ pub enum CollisionBody {
     Circle,
 }
 
-#[derive(Component)]
+#[derive(Component, Debug, Clone, Copy)]
 pub struct CollisionProperties {
     pub body: CollisionBody,
     pub group: CollisionMask,
     pub mask: CollisionMask,
     pub bounciness: f32,
+    /// Fraction of tangential slip removed on contact, multiplied between both bodies
+    pub friction: f32,
 }
 
 impl Default for CollisionProperties {
@@ -88,6 +92,7 @@ impl Default for CollisionProperties {
             group: CollisionMask::default(),
             mask: CollisionMask::default(),
             bounciness: 1.0,
+            friction: 0.0,
         }
     }
 }
@@ -0,0 +1,34 @@
+use bevy::prelude::*;
+
+pub const DEFAULT_TICK_RATE: f64 = 64.0;
+pub const DEFAULT_SUBSTEPS: u32 = 4;
+
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct PhysicsConfig {
+    /// Physics ticks per second, drives the `FixedUpdate` timestep
+    pub tick_rate: f64,
+    /// Number of integration and collision passes per tick
+    pub substeps: u32,
+    /// Orders collision pairs and events by `BodyId` so runs are reproducible
+    pub deterministic: bool,
+}
+
+impl PhysicsConfig {
+    pub fn substep_seconds(&self, delta_seconds: f32) -> f32 {
+        delta_seconds / self.substeps.max(1) as f32
+    }
+}
+
+impl Default for PhysicsConfig {
+    fn default() -> Self {
+        Self {
+            tick_rate: DEFAULT_TICK_RATE,
+            substeps: DEFAULT_SUBSTEPS,
+            deterministic: false,
+        }
+    }
+}
+
+pub fn apply_physics_config(config: Res<PhysicsConfig>, mut fixed_time: ResMut<Time<Fixed>>) {
+    fixed_time.set_timestep_hz(config.tick_rate);
+}
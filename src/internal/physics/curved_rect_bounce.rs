@@ -1,6 +1,6 @@
 use bevy::prelude::*;
 
-use super::{CollisionEvent, Velocity};
+use super::{read_ordered_collisions, BodyId, CollisionEvent, PhysicsConfig, RigidBody, Velocity};
 
 #[derive(Component)]
 pub struct CurvedRectBounce {
@@ -10,20 +10,25 @@ pub struct CurvedRectBounce {
 pub fn handle_curved_bounce(
     mut ev_collision: EventReader<CollisionEvent>,
     curved_query: Query<(&CurvedRectBounce, &Transform)>,
-    mut other_query: Query<(&mut Velocity, &Transform)>,
+    mut other_query: Query<(&mut Velocity, &Transform, &RigidBody)>,
+    body_id_query: Query<&BodyId>,
+    config: Res<PhysicsConfig>,
 ) {
-    for collision in ev_collision.read() {
+    for collision in
+        read_ordered_collisions(&mut ev_collision, &body_id_query, config.deterministic)
+    {
+        if collision.pass_through {
+            continue;
+        }
         let curved_rect = if let Ok(curved_rect) = curved_query.get(collision.collidees[0]) {
             curved_rect
         } else {
             continue;
         };
-        let (mut velocity, other_transform) =
-            if let Ok(other) = other_query.get_mut(collision.collidees[1]) {
-                other
-            } else {
-                continue;
-            };
+        let (mut velocity, other_transform) = match other_query.get_mut(collision.collidees[1]) {
+            Ok((velocity, transform, RigidBody::Dynamic)) => (velocity, transform),
+            _ => continue,
+        };
 
         let rect_center = curved_rect.1.translation;
         let rect_size = curved_rect.1.scale;
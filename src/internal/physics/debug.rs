@@ -0,0 +1,169 @@
+use bevy::prelude::*;
+
+use super::{
+    CollisionBody, CollisionEvent, CollisionGroup, CollisionMask, CollisionProperties,
+    CollisionResult, Velocity, COLLISION_GROUPS,
+};
+
+const VELOCITY_ARROW_SCALE: f32 = 0.2;
+const NORMAL_ARROW_LENGTH: f32 = 30.0;
+const CORRIGATION_ARROW_SCALE: f32 = 10.0;
+const ARROW_HEAD_LENGTH: f32 = 6.0;
+const COLLISION_DISPLAY_SECONDS: f32 = 0.5;
+
+const VELOCITY_COLOR: Color = Color::ORANGE;
+const NORMAL_COLOR: Color = Color::FUCHSIA;
+const CORRIGATION_COLOR: Color = Color::CYAN;
+
+#[derive(Resource)]
+pub struct PhysicsDebugConfig {
+    pub enabled: bool,
+    pub toggle_key: KeyCode,
+}
+
+impl Default for PhysicsDebugConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            toggle_key: KeyCode::F3,
+        }
+    }
+}
+
+struct DebugCollision {
+    position: Vec2,
+    collision_result: CollisionResult,
+    remaining: f32,
+}
+
+#[derive(Resource, Default)]
+struct DebugCollisions(Vec<DebugCollision>);
+
+pub struct PhysicsDebugPlugin;
+
+impl Plugin for PhysicsDebugPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PhysicsDebugConfig>()
+            .init_resource::<DebugCollisions>()
+            .add_systems(
+                Update,
+                (
+                    toggle_debug,
+                    record_collisions,
+                    (draw_bodies, draw_velocities, draw_collisions).run_if(debug_enabled),
+                )
+                    .chain(),
+            );
+    }
+}
+
+fn debug_enabled(config: Res<PhysicsDebugConfig>) -> bool {
+    config.enabled
+}
+
+fn toggle_debug(
+    mut config: ResMut<PhysicsDebugConfig>,
+    mut collisions: ResMut<DebugCollisions>,
+    input: Res<Input<KeyCode>>,
+) {
+    if input.just_pressed(config.toggle_key) {
+        config.enabled = !config.enabled;
+        collisions.0.clear();
+    }
+}
+
+fn group_color(group: &CollisionMask) -> Color {
+    match COLLISION_GROUPS
+        .iter()
+        .find(|&&collision_group| group.contains_group(collision_group))
+    {
+        Some(CollisionGroup::Paddle) => Color::BLUE,
+        Some(CollisionGroup::Ball) => Color::RED,
+        Some(CollisionGroup::Block) => Color::GREEN,
+        Some(CollisionGroup::Wall) => Color::DARK_GRAY,
+        Some(CollisionGroup::Powerup) => Color::GOLD,
+        Some(CollisionGroup::Laser) => Color::FUCHSIA,
+        None => Color::BLACK,
+    }
+}
+
+fn arrow_2d(gizmos: &mut Gizmos, start: Vec2, vector: Vec2, color: Color) {
+    gizmos.ray_2d(start, vector, color);
+    let Some(direction) = vector.try_normalize() else {
+        return;
+    };
+    let end = start + vector;
+    let back = -direction * ARROW_HEAD_LENGTH;
+    gizmos.ray_2d(end, back.rotate(Vec2::from_angle(0.5)), color);
+    gizmos.ray_2d(end, back.rotate(Vec2::from_angle(-0.5)), color);
+}
+
+/// Runs while the overlay is disabled too, so no stale collisions are shown once it's enabled
+fn record_collisions(
+    mut ev_collision: EventReader<CollisionEvent>,
+    mut collisions: ResMut<DebugCollisions>,
+    config: Res<PhysicsDebugConfig>,
+    transform_query: Query<&Transform>,
+    time: Res<Time>,
+) {
+    if !config.enabled {
+        ev_collision.clear();
+        return;
+    }
+    collisions.0.retain_mut(|collision| {
+        collision.remaining -= time.delta_seconds();
+        collision.remaining > 0.0
+    });
+    for collision in ev_collision.read() {
+        // The second collidee is the one pushed along the normal
+        if let Ok(transform) = transform_query.get(collision.collidees[1]) {
+            collisions.0.push(DebugCollision {
+                position: transform.translation.truncate(),
+                collision_result: collision.collision_result,
+                remaining: COLLISION_DISPLAY_SECONDS,
+            });
+        }
+    }
+}
+
+fn draw_bodies(mut gizmos: Gizmos, query: Query<(&Transform, &CollisionProperties)>) {
+    for (transform, collision_properties) in &query {
+        let position = transform.translation.truncate();
+        let size = transform.scale.truncate();
+        let color = group_color(&collision_properties.group);
+        match collision_properties.body {
+            CollisionBody::Rect => gizmos.rect_2d(position, 0.0, size, color),
+            CollisionBody::Circle => {
+                gizmos.circle_2d(position, size.x / 2.0, color);
+            }
+        }
+    }
+}
+
+fn draw_velocities(mut gizmos: Gizmos, query: Query<(&Transform, &Velocity)>) {
+    for (transform, velocity) in &query {
+        arrow_2d(
+            &mut gizmos,
+            transform.translation.truncate(),
+            velocity.0 * VELOCITY_ARROW_SCALE,
+            VELOCITY_COLOR,
+        );
+    }
+}
+
+fn draw_collisions(mut gizmos: Gizmos, collisions: Res<DebugCollisions>) {
+    for collision in &collisions.0 {
+        arrow_2d(
+            &mut gizmos,
+            collision.position,
+            collision.collision_result.collision_normal * NORMAL_ARROW_LENGTH,
+            NORMAL_COLOR,
+        );
+        arrow_2d(
+            &mut gizmos,
+            collision.position,
+            collision.collision_result.corrigation_vector * CORRIGATION_ARROW_SCALE,
+            CORRIGATION_COLOR,
+        );
+    }
+}
@@ -0,0 +1,204 @@
+use bevy::prelude::*;
+
+use super::{
+    body_sort_key, local_time_scale, BodyId, CollisionMask, CollisionProperties, GameTime,
+    LocalTimeScale, PhysicsConfig, RigidBody, Velocity,
+};
+
+/// Global gravity applied to dynamic bodies, zero by default
+#[derive(Resource, Debug, Clone, Copy, Default, Deref, DerefMut)]
+pub struct Gravity(pub Vec2);
+
+/// Multiplies the gravity applied to a body, defaults to 1 when missing
+#[derive(Component, Debug, Clone, Copy, Deref, DerefMut)]
+pub struct GravityScale(pub f32);
+
+/// Replaces the global `Gravity` for a single body
+#[derive(Component, Debug, Clone, Copy, Deref, DerefMut)]
+pub struct LocalGravity(pub Vec2);
+
+/// Fraction of velocity lost per second
+#[derive(Component, Debug, Clone, Copy, Deref, DerefMut)]
+pub struct LinearDamping(pub f32);
+
+#[derive(Debug, Clone, Copy)]
+pub enum ForceFieldArea {
+    Rect(Vec2),
+    Circle(f32),
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum ForceFieldEffect {
+    /// Constant acceleration inside the area
+    Wind(Vec2),
+    /// Acceleration towards the center, strongest at the center and fading out to the edge
+    Attractor(f32),
+    /// Acceleration away from the center, strongest at the center and fading out to the edge
+    Repulsor(f32),
+}
+
+/**
+ * Area around the entity's translation that accelerates dynamic bodies inside it.
+ * Only bodies whose collision group is contained in `mask` are affected.
+ */
+#[derive(Component, Debug, Clone, Copy)]
+pub struct ForceField {
+    pub area: ForceFieldArea,
+    pub effect: ForceFieldEffect,
+    pub mask: CollisionMask,
+}
+
+pub fn force_field_acceleration(
+    field: &ForceField,
+    field_position: Vec2,
+    body_position: Vec2,
+) -> Vec2 {
+    let offset = body_position - field_position;
+    let reach = match field.area {
+        ForceFieldArea::Rect(size) => {
+            if offset.x.abs() > size.x / 2.0 || offset.y.abs() > size.y / 2.0 {
+                return Vec2::ZERO;
+            }
+            (size / 2.0).length()
+        }
+        ForceFieldArea::Circle(radius) => {
+            if offset.length() > radius {
+                return Vec2::ZERO;
+            }
+            radius
+        }
+    };
+
+    let falloff = 1.0 - (offset.length() / reach).min(1.0);
+    match field.effect {
+        ForceFieldEffect::Wind(acceleration) => acceleration,
+        ForceFieldEffect::Attractor(strength) => -offset.normalize_or_zero() * strength * falloff,
+        ForceFieldEffect::Repulsor(strength) => offset.normalize_or_zero() * strength * falloff,
+    }
+}
+
+pub fn apply_forces(
+    mut body_query: Query<(
+        &mut Velocity,
+        &Transform,
+        &RigidBody,
+        Option<&GravityScale>,
+        Option<&LocalGravity>,
+        Option<&LinearDamping>,
+        Option<&CollisionProperties>,
+        Option<&LocalTimeScale>,
+    )>,
+    field_query: Query<(Entity, &Transform, &ForceField, Option<&BodyId>)>,
+    gravity: Res<Gravity>,
+    time: GameTime,
+    config: Res<PhysicsConfig>,
+) {
+    let substep_seconds = config.substep_seconds(time.delta_seconds());
+
+    let mut fields: Vec<_> = field_query.iter().collect();
+    if config.deterministic {
+        fields.sort_by_key(|(entity, _, _, body_id)| body_sort_key(*body_id, *entity));
+    }
+
+    for (
+        mut velocity,
+        transform,
+        rigid_body,
+        gravity_scale,
+        local_gravity,
+        linear_damping,
+        collision_properties,
+        time_scale,
+    ) in &mut body_query
+    {
+        if *rigid_body != RigidBody::Dynamic {
+            continue;
+        }
+        let delta_seconds = substep_seconds * local_time_scale(time_scale);
+
+        let mut acceleration = local_gravity.map_or(gravity.0, |local_gravity| local_gravity.0)
+            * gravity_scale.map_or(1.0, |gravity_scale| gravity_scale.0);
+
+        if let Some(collision_properties) = collision_properties {
+            let body_position = transform.translation.truncate();
+            for (_, field_transform, field, _) in &fields {
+                if field.mask.contains(&collision_properties.group) {
+                    acceleration += force_field_acceleration(
+                        field,
+                        field_transform.translation.truncate(),
+                        body_position,
+                    );
+                }
+            }
+        }
+
+        velocity.0 += acceleration * delta_seconds;
+
+        if let Some(linear_damping) = linear_damping {
+            velocity.0 *= (1.0 - linear_damping.0 * delta_seconds).max(0.0);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rstest::rstest;
+
+    use crate::internal::physics::CollisionGroup;
+
+    #[rstest]
+    #[case(
+        ForceFieldArea::Rect(Vec2::new(100.0, 100.0)),
+        ForceFieldEffect::Wind(Vec2::new(10.0, 0.0)),
+        Vec2::new(40.0, 40.0),
+        Vec2::new(10.0, 0.0)
+    )]
+    #[case(
+        ForceFieldArea::Rect(Vec2::new(100.0, 100.0)),
+        ForceFieldEffect::Wind(Vec2::new(10.0, 0.0)),
+        Vec2::new(60.0, 0.0),
+        Vec2::ZERO
+    )]
+    #[case(
+        ForceFieldArea::Circle(100.0),
+        ForceFieldEffect::Attractor(10.0),
+        Vec2::new(50.0, 0.0),
+        Vec2::new(-5.0, 0.0)
+    )]
+    #[case(
+        ForceFieldArea::Circle(100.0),
+        ForceFieldEffect::Repulsor(10.0),
+        Vec2::new(0.0, -50.0),
+        Vec2::new(0.0, -5.0)
+    )]
+    #[case(
+        ForceFieldArea::Circle(100.0),
+        ForceFieldEffect::Attractor(10.0),
+        Vec2::new(0.0, 0.0),
+        Vec2::ZERO
+    )]
+    #[case(
+        ForceFieldArea::Circle(100.0),
+        ForceFieldEffect::Attractor(10.0),
+        Vec2::new(0.0, 150.0),
+        Vec2::ZERO
+    )]
+    fn test_force_field_acceleration(
+        #[case] area: ForceFieldArea,
+        #[case] effect: ForceFieldEffect,
+        #[case] body_position: Vec2,
+        #[case] expected: Vec2,
+    ) {
+        let field = ForceField {
+            area,
+            effect,
+            mask: CollisionMask::new(&[CollisionGroup::Ball]),
+        };
+
+        let acceleration = force_field_acceleration(&field, Vec2::ZERO, body_position);
+
+        assert_eq!(acceleration, expected);
+    }
+}
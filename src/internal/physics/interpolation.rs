@@ -0,0 +1,116 @@
+use bevy::prelude::*;
+
+/**
+ * Smooths the rendered `Transform` between the last two physics states.
+ * Entities with this component should only be moved inside `FixedUpdate`,
+ * call `reset` after teleporting them so the jump is not smoothed.
+ */
+#[derive(Component, Default)]
+pub struct TransformInterpolation {
+    previous: Option<Vec3>,
+    current: Option<Vec3>,
+}
+
+impl TransformInterpolation {
+    /// Forgets the stored physics states, the next frame renders the `Transform` as is
+    pub fn reset(&mut self) {
+        self.previous = None;
+        self.current = None;
+    }
+
+    pub fn interpolated(&self, alpha: f32) -> Option<Vec3> {
+        match (self.previous, self.current) {
+            (Some(previous), Some(current)) => Some(previous.lerp(current, alpha)),
+            _ => None,
+        }
+    }
+}
+
+pub fn restore_physics_transforms(mut query: Query<(&mut Transform, &TransformInterpolation)>) {
+    for (mut transform, interpolation) in &mut query {
+        if let Some(current) = interpolation.current {
+            transform.translation = current;
+        }
+    }
+}
+
+pub fn store_physics_transforms(mut query: Query<(&Transform, &mut TransformInterpolation)>) {
+    for (transform, mut interpolation) in &mut query {
+        interpolation.previous = Some(interpolation.current.unwrap_or(transform.translation));
+        interpolation.current = Some(transform.translation);
+    }
+}
+
+pub fn interpolate_transforms(
+    mut query: Query<(&mut Transform, &TransformInterpolation)>,
+    fixed_time: Res<Time<Fixed>>,
+) {
+    let alpha = fixed_time.overstep_percentage();
+    for (mut transform, interpolation) in &mut query {
+        if let Some(translation) = interpolation.interpolated(alpha) {
+            transform.translation = translation;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use bevy::ecs::system::RunSystemOnce;
+
+    #[test]
+    fn test_interpolates_between_physics_states() {
+        let mut world = World::new();
+        let entity = world
+            .spawn((Transform::default(), TransformInterpolation::default()))
+            .id();
+
+        world.run_system_once(store_physics_transforms);
+        world.get_mut::<Transform>(entity).unwrap().translation = Vec3::new(10.0, 20.0, 0.0);
+        world.run_system_once(store_physics_transforms);
+
+        let interpolation = world.get::<TransformInterpolation>(entity).unwrap();
+        assert_eq!(interpolation.interpolated(0.0), Some(Vec3::ZERO));
+        assert_eq!(
+            interpolation.interpolated(0.5),
+            Some(Vec3::new(5.0, 10.0, 0.0))
+        );
+        assert_eq!(
+            interpolation.interpolated(1.0),
+            Some(Vec3::new(10.0, 20.0, 0.0))
+        );
+    }
+
+    #[test]
+    fn test_reset_skips_interpolation() {
+        let mut world = World::new();
+        world.init_resource::<Time<Fixed>>();
+        let entity = world
+            .spawn((Transform::default(), TransformInterpolation::default()))
+            .id();
+        world.run_system_once(store_physics_transforms);
+        world.run_system_once(store_physics_transforms);
+
+        let teleport = Vec3::new(100.0, -50.0, 0.0);
+        world.get_mut::<Transform>(entity).unwrap().translation = teleport;
+        world
+            .get_mut::<TransformInterpolation>(entity)
+            .unwrap()
+            .reset();
+
+        world.run_system_once(interpolate_transforms);
+        assert_eq!(
+            world.get::<Transform>(entity).unwrap().translation,
+            teleport
+        );
+
+        world.run_system_once(restore_physics_transforms);
+        world.run_system_once(store_physics_transforms);
+        world.run_system_once(interpolate_transforms);
+        assert_eq!(
+            world.get::<Transform>(entity).unwrap().translation,
+            teleport
+        );
+    }
+}
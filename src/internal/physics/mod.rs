@@ -1,13 +1,35 @@
+mod spin;
 mod velocity;
 mod plugin;
+mod debug;
+mod body_id;
+mod config;
+mod forces;
 mod collider;
+mod rigid_body;
+mod speed_limit;
+mod time_scale;
+mod interpolation;
+mod pass_through;
+mod spatial_query;
 mod curved_rect_bounce;
 mod collision_properties;
 
 pub mod collision;
 
 pub use plugin::*;
+pub use debug::*;
+pub use body_id::*;
+pub use config::*;
+pub use forces::*;
 pub use collider::*;
+pub use rigid_body::*;
+pub use speed_limit::*;
+pub use time_scale::*;
+pub use spin::*;
 pub use velocity::*;
+pub use interpolation::*;
+pub use pass_through::*;
+pub use spatial_query::*;
 pub use curved_rect_bounce::*;
-pub use collision_properties::*;
\ No newline at end of file
+pub use collision_properties::*;
@@ -0,0 +1,21 @@
+use bevy::prelude::*;
+
+use super::{CollisionMask, CollisionProperties};
+
+/**
+ * Lets a body pass through the groups in `mask`.
+ * Such collisions are still reported as `CollisionEvent`s, but neither body is corrected or bounced.
+ */
+#[derive(Component, Debug, Clone, Copy)]
+pub struct PassThrough {
+    pub mask: CollisionMask,
+}
+
+pub fn passes_through(
+    a: (Option<&PassThrough>, &CollisionProperties),
+    b: (Option<&PassThrough>, &CollisionProperties),
+) -> bool {
+    a.0.is_some_and(|pass_through| pass_through.mask.contains(&b.1.group))
+        || b.0
+            .is_some_and(|pass_through| pass_through.mask.contains(&a.1.group))
+}
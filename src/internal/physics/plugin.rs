@@ -1,29 +1,404 @@
-use bevy::prelude::*;
+use bevy::{ecs::schedule::ScheduleLabel, prelude::*, transform::TransformSystem};
 
 use super::{
-    apply_velocity,
+    apply_angular_velocity, apply_forces, apply_physics_config, apply_speed_limit,
+    apply_spin_effect, apply_velocity, assign_body_ids,
     collider::{check_for_collisions, handle_collision_bounce, handle_correction, CollisionEvent},
-    handle_curved_bounce,
+    handle_curved_bounce, interpolate_transforms, restore_physics_transforms,
+    store_physics_transforms, BodyIdCounter, Gravity, PhysicsConfig, TimeScale,
 };
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, SystemSet)]
 pub struct PhysicsLabel;
 
+/// Runs `PhysicsConfig::substeps` times per `FixedUpdate` tick
+#[derive(Debug, Clone, PartialEq, Eq, Hash, ScheduleLabel)]
+pub struct PhysicsSubstep;
+
 pub struct PhysicsPlugin;
 
 impl Plugin for PhysicsPlugin {
     fn build(&self, app: &mut App) {
-        app.add_event::<CollisionEvent>().add_systems(
-            FixedUpdate,
-            (
-                apply_velocity,
-                check_for_collisions,
-                handle_correction,
-                handle_collision_bounce,
-                handle_curved_bounce,
+        app.add_event::<CollisionEvent>()
+            .init_resource::<PhysicsConfig>()
+            .init_resource::<BodyIdCounter>()
+            .init_resource::<Gravity>()
+            .init_resource::<TimeScale>()
+            .add_systems(
+                PhysicsSubstep,
+                (
+                    apply_forces,
+                    apply_velocity,
+                    apply_angular_velocity,
+                    apply_spin_effect,
+                    check_for_collisions,
+                    handle_correction,
+                    handle_collision_bounce,
+                    handle_curved_bounce,
+                    apply_speed_limit,
+                )
+                    .chain(),
+            )
+            .add_systems(
+                FixedUpdate,
+                (
+                    assign_body_ids,
+                    apply_deferred,
+                    run_physics_substeps,
+                    store_physics_transforms,
+                )
+                    .chain()
+                    .in_set(PhysicsLabel),
             )
-                .chain()
-                .in_set(PhysicsLabel),
+            .add_systems(
+                First,
+                apply_physics_config.run_if(resource_changed::<PhysicsConfig>()),
+            )
+            .add_systems(PreUpdate, restore_physics_transforms)
+            .add_systems(
+                PostUpdate,
+                interpolate_transforms.before(TransformSystem::TransformPropagate),
+            );
+    }
+}
+
+fn run_physics_substeps(world: &mut World) {
+    let substeps = world.resource::<PhysicsConfig>().substeps.max(1);
+    for _ in 0..substeps {
+        world.run_schedule(PhysicsSubstep);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::hash_map::DefaultHasher,
+        hash::{Hash, Hasher},
+        time::Duration,
+    };
+
+    use rstest::rstest;
+
+    use super::*;
+    use crate::internal::physics::{
+        BodyId, CollisionBody, CollisionGroup, CollisionMask, CollisionProperties, LocalTimeScale,
+        Mass, PassThrough, RigidBody, UnscaledTime, Velocity, DEFAULT_TICK_RATE,
+    };
+
+    #[derive(Resource, Default)]
+    struct SubstepCount(u32);
+
+    fn count_substeps(mut count: ResMut<SubstepCount>) {
+        count.0 += 1;
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(4)]
+    #[case(0)]
+    fn test_substeps_split_tick(#[case] substeps: u32) {
+        let mut app = App::new();
+        app.add_plugins(PhysicsPlugin)
+            .insert_resource(PhysicsConfig {
+                substeps,
+                ..default()
+            })
+            .init_resource::<SubstepCount>()
+            .add_systems(PhysicsSubstep, count_substeps);
+        let mut time = Time::<()>::default();
+        time.advance_by(Duration::from_secs_f32(0.5));
+        app.insert_resource(time);
+        let body = app
+            .world
+            .spawn((Transform::default(), Velocity(Vec2::new(100.0, -40.0))))
+            .id();
+
+        app.world.run_schedule(FixedUpdate);
+
+        assert_eq!(app.world.resource::<SubstepCount>().0, substeps.max(1));
+        let translation = app.world.get::<Transform>(body).unwrap().translation;
+        assert!((translation - Vec3::new(50.0, -20.0, 0.0)).length() < 1e-4);
+    }
+
+    #[derive(Component)]
+    struct Shuffled;
+
+    fn spawn_body(app: &mut App, shuffle: bool, bundle: impl Bundle) {
+        let mut entity = app.world.spawn(bundle);
+        if shuffle {
+            entity.insert(Shuffled);
+        }
+    }
+
+    fn test_app(config: PhysicsConfig) -> App {
+        let mut app = App::new();
+        app.add_plugins(PhysicsPlugin).insert_resource(config);
+        let mut time = Time::<()>::default();
+        time.advance_by(Duration::from_secs_f64(1. / DEFAULT_TICK_RATE));
+        app.insert_resource(time);
+        app
+    }
+
+    fn run_ticks(app: &mut App, ticks: usize) {
+        for _ in 0..ticks {
+            app.world.run_schedule(FixedUpdate);
+            app.world.resource_mut::<Events<CollisionEvent>>().update();
+        }
+    }
+
+    fn ball_collision_properties() -> CollisionProperties {
+        CollisionProperties {
+            body: CollisionBody::Circle,
+            group: CollisionMask::new(&[CollisionGroup::Ball]),
+            mask: CollisionMask::new(&[CollisionGroup::Ball, CollisionGroup::Wall]),
+            ..default()
+        }
+    }
+
+    fn run_simulation(shuffle: bool, ticks: usize) -> u64 {
+        let mut app = test_app(PhysicsConfig {
+            deterministic: true,
+            ..default()
+        });
+
+        let static_body = CollisionProperties {
+            body: CollisionBody::Rect,
+            group: CollisionMask::new(&[CollisionGroup::Wall]),
+            mask: CollisionMask::new(&[CollisionGroup::Ball]),
+            ..default()
+        };
+        for (position, size) in [
+            (Vec2::new(-200., 0.), Vec2::new(10., 410.)),
+            (Vec2::new(200., 0.), Vec2::new(10., 410.)),
+            (Vec2::new(0., -200.), Vec2::new(410., 10.)),
+            (Vec2::new(0., 200.), Vec2::new(410., 10.)),
+        ] {
+            app.world.spawn((
+                Transform::from_translation(position.extend(0.)).with_scale(size.extend(1.)),
+                static_body,
+            ));
+        }
+        for index in 0..20 {
+            let position = Vec2::new(
+                -150. + (index % 5) as f32 * 70.,
+                50. + (index / 5) as f32 * 35.,
+            );
+            spawn_body(
+                &mut app,
+                shuffle && index % 3 == 0,
+                (
+                    Transform::from_translation(position.extend(0.))
+                        .with_scale(Vec3::new(60., 30., 1.)),
+                    static_body,
+                ),
+            );
+        }
+        for index in 0..10 {
+            let direction = Vec2::from_angle(index as f32 * 0.6 + 0.3);
+            spawn_body(
+                &mut app,
+                shuffle && index % 2 == 0,
+                (
+                    Transform::from_translation(Vec3::new(-100. + index as f32 * 20., -100., 0.))
+                        .with_scale(Vec3::new(10., 10., 1.)),
+                    RigidBody::Dynamic,
+                    Velocity(direction * 400.),
+                    CollisionProperties {
+                        mask: CollisionMask::new(&[CollisionGroup::Wall]),
+                        ..ball_collision_properties()
+                    },
+                ),
+            );
+        }
+
+        for _ in 0..ticks {
+            app.world.run_schedule(FixedUpdate);
+            app.world.resource_mut::<Events<CollisionEvent>>().update();
+        }
+
+        let mut bodies: Vec<_> = app
+            .world
+            .query::<(&BodyId, &Transform, Option<&Velocity>)>()
+            .iter(&app.world)
+            .collect();
+        bodies.sort_by_key(|(body_id, _, _)| **body_id);
+
+        let mut hasher = DefaultHasher::new();
+        for (body_id, transform, velocity) in bodies {
+            body_id.hash(&mut hasher);
+            transform.translation.x.to_bits().hash(&mut hasher);
+            transform.translation.y.to_bits().hash(&mut hasher);
+            if let Some(velocity) = velocity {
+                velocity.x.to_bits().hash(&mut hasher);
+                velocity.y.to_bits().hash(&mut hasher);
+            }
+        }
+        hasher.finish()
+    }
+
+    #[test]
+    fn test_deterministic_simulation() {
+        let expected = run_simulation(false, 10_000);
+
+        assert_eq!(run_simulation(false, 10_000), expected);
+        assert_eq!(run_simulation(true, 10_000), expected);
+    }
+
+    #[test]
+    fn test_kinematic_pushes_dynamic() {
+        let mut app = test_app(PhysicsConfig::default());
+        let wall = app
+            .world
+            .spawn((
+                Transform::from_xyz(0., 0., 0.).with_scale(Vec3::new(10., 100., 1.)),
+                RigidBody::Kinematic,
+                Velocity(Vec2::new(100., 0.)),
+                CollisionProperties {
+                    body: CollisionBody::Rect,
+                    group: CollisionMask::new(&[CollisionGroup::Wall]),
+                    mask: CollisionMask::new(&[CollisionGroup::Ball]),
+                    ..default()
+                },
+            ))
+            .id();
+        let ball = app
+            .world
+            .spawn((
+                Transform::from_xyz(20., 0., 0.).with_scale(Vec3::new(10., 10., 1.)),
+                RigidBody::Dynamic,
+                Velocity(Vec2::ZERO),
+                ball_collision_properties(),
+            ))
+            .id();
+
+        run_ticks(&mut app, 16);
+
+        assert_eq!(
+            app.world.get::<Velocity>(wall).unwrap().0,
+            Vec2::new(100., 0.)
+        );
+        let wall_x = app.world.get::<Transform>(wall).unwrap().translation.x;
+        let ball_x = app.world.get::<Transform>(ball).unwrap().translation.x;
+        assert_eq!(wall_x, 25.);
+        assert!(ball_x >= wall_x + 10.);
+        assert!(app.world.get::<Velocity>(ball).unwrap().x > 100.);
+    }
+
+    #[test]
+    fn test_pass_through_reports_collision_without_response() {
+        let mut app = test_app(PhysicsConfig::default());
+        let wall = app
+            .world
+            .spawn((
+                Transform::from_xyz(0., 0., 0.).with_scale(Vec3::new(10., 100., 1.)),
+                CollisionProperties {
+                    body: CollisionBody::Rect,
+                    group: CollisionMask::new(&[CollisionGroup::Wall]),
+                    mask: CollisionMask::new(&[CollisionGroup::Ball]),
+                    ..default()
+                },
+            ))
+            .id();
+        let ball = app
+            .world
+            .spawn((
+                Transform::from_xyz(-20., 0., 0.).with_scale(Vec3::new(10., 10., 1.)),
+                RigidBody::Dynamic,
+                Velocity(Vec2::new(640., 0.)),
+                ball_collision_properties(),
+                PassThrough {
+                    mask: CollisionMask::new(&[CollisionGroup::Wall]),
+                },
+            ))
+            .id();
+
+        app.world.run_schedule(FixedUpdate);
+
+        let collisions: Vec<_> = app
+            .world
+            .resource_mut::<Events<CollisionEvent>>()
+            .drain()
+            .collect();
+        assert!(collisions
+            .iter()
+            .any(|collision| collision.pass_through && collision.collidees.contains(&wall)));
+        assert_eq!(
+            app.world.get::<Velocity>(ball).unwrap().0,
+            Vec2::new(640., 0.)
         );
+        assert_eq!(
+            app.world.get::<Transform>(ball).unwrap().translation.x,
+            -10.
+        );
+    }
+
+    #[test]
+    fn test_time_scale_slows_bodies() {
+        let mut app = test_app(PhysicsConfig::default());
+        app.insert_resource(TimeScale(0.5));
+        let body = app
+            .world
+            .spawn((
+                Transform::default(),
+                RigidBody::Dynamic,
+                Velocity(Vec2::new(64., 0.)),
+            ))
+            .id();
+        let slow_body = app
+            .world
+            .spawn((
+                Transform::default(),
+                RigidBody::Dynamic,
+                Velocity(Vec2::new(64., 0.)),
+                LocalTimeScale(0.5),
+            ))
+            .id();
+        let unscaled_body = app
+            .world
+            .spawn((
+                Transform::default(),
+                RigidBody::Kinematic,
+                Velocity(Vec2::new(64., 0.)),
+                UnscaledTime,
+            ))
+            .id();
+
+        run_ticks(&mut app, 64);
+
+        let x = |entity| app.world.get::<Transform>(entity).unwrap().translation.x;
+        assert!((x(body) - 32.).abs() < 1e-3);
+        assert!((x(slow_body) - 16.).abs() < 1e-3);
+        assert!((x(unscaled_body) - 64.).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_dynamic_pair_exchanges_momentum_by_mass() {
+        let mut app = test_app(PhysicsConfig::default());
+        let light = app
+            .world
+            .spawn((
+                Transform::from_xyz(-20., 0., 0.).with_scale(Vec3::new(10., 10., 1.)),
+                RigidBody::Dynamic,
+                Velocity(Vec2::new(100., 0.)),
+                ball_collision_properties(),
+            ))
+            .id();
+        let heavy = app
+            .world
+            .spawn((
+                Transform::from_xyz(0., 0., 0.).with_scale(Vec3::new(10., 10., 1.)),
+                RigidBody::Dynamic,
+                Mass(3.),
+                Velocity(Vec2::ZERO),
+                ball_collision_properties(),
+            ))
+            .id();
+
+        run_ticks(&mut app, 16);
+
+        let light_velocity = app.world.get::<Velocity>(light).unwrap().0;
+        let heavy_velocity = app.world.get::<Velocity>(heavy).unwrap().0;
+        assert!((light_velocity - Vec2::new(-50., 0.)).length() < 1e-3);
+        assert!((heavy_velocity - Vec2::new(50., 0.)).length() < 1e-3);
     }
 }
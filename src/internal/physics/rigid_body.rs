@@ -0,0 +1,37 @@
+use bevy::prelude::*;
+
+/**
+ * How a body takes part in collision response. Bodies without this component are `Static`.
+ * - `Static` never moves.
+ * - `Kinematic` is moved by its `Velocity` only and is never pushed or bounced,
+ *   but pushes dynamic bodies and lends them its velocity.
+ * - `Dynamic` is moved by its `Velocity` and responds to collisions according to its `Mass`.
+ */
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RigidBody {
+    #[default]
+    Static,
+    Kinematic,
+    Dynamic,
+}
+
+/// Mass of a dynamic body, defaults to 1 when missing
+#[derive(Component, Debug, Clone, Copy, Deref, DerefMut)]
+pub struct Mass(pub f32);
+
+impl Default for Mass {
+    fn default() -> Self {
+        Self(1.0)
+    }
+}
+
+pub fn inverse_mass(rigid_body: Option<&RigidBody>, mass: Option<&Mass>) -> f32 {
+    match rigid_body.copied().unwrap_or_default() {
+        RigidBody::Dynamic => 1.0 / mass.copied().unwrap_or_default().0,
+        RigidBody::Static | RigidBody::Kinematic => 0.0,
+    }
+}
+
+/// Fraction of this body's velocity added to bodies bouncing off it
+#[derive(Component, Deref, DerefMut)]
+pub struct MomentumTransfer(pub f32);
@@ -0,0 +1,215 @@
+use bevy::{ecs::system::SystemParam, prelude::*};
+
+use super::{
+    collision::{
+        circle_cast_rect_intersection, circle_circle_collision, ray_circle_intersection,
+        ray_rect_intersection, rect_circle_collision, rect_rect_collision, RayIntersection,
+    },
+    CollisionBody, CollisionMask, CollisionProperties,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RayHit {
+    pub entity: Entity,
+    pub point: Vec2,
+    pub normal: Vec2,
+    pub distance: f32,
+}
+
+/**
+ * Queries against every entity with `CollisionProperties`.
+ * Bodies are considered when their group is contained in the given mask.
+ */
+#[derive(SystemParam)]
+pub struct SpatialQuery<'w, 's> {
+    bodies: Query<'w, 's, (Entity, &'static Transform, &'static CollisionProperties)>,
+}
+
+impl<'w, 's> SpatialQuery<'w, 's> {
+    /// Returns the closest body hit by the ray within `max_distance`
+    pub fn ray_cast(
+        &self,
+        origin: Vec2,
+        direction: Vec2,
+        max_distance: f32,
+        mask: CollisionMask,
+    ) -> Option<RayHit> {
+        let direction = direction.try_normalize()?;
+        self.cast(
+            origin,
+            direction,
+            max_distance,
+            mask,
+            |position, size, body| match body {
+                CollisionBody::Rect => ray_rect_intersection(origin, direction, position, size),
+                CollisionBody::Circle => {
+                    ray_circle_intersection(origin, direction, position, size.x / 2.0)
+                }
+            },
+        )
+    }
+
+    /// Returns the closest body hit by a circle of `radius` swept along the ray.
+    /// The hit point is the center of the circle at the moment of contact.
+    pub fn circle_cast(
+        &self,
+        origin: Vec2,
+        radius: f32,
+        direction: Vec2,
+        max_distance: f32,
+        mask: CollisionMask,
+    ) -> Option<RayHit> {
+        let direction = direction.try_normalize()?;
+        self.cast(
+            origin,
+            direction,
+            max_distance,
+            mask,
+            |position, size, body| match body {
+                CollisionBody::Rect => {
+                    circle_cast_rect_intersection(origin, direction, radius, position, size)
+                }
+                CollisionBody::Circle => {
+                    ray_circle_intersection(origin, direction, position, size.x / 2.0 + radius)
+                }
+            },
+        )
+    }
+
+    pub fn point_overlap(&self, point: Vec2, mask: CollisionMask) -> Vec<Entity> {
+        self.overlap(mask, |position, size, body| match body {
+            CollisionBody::Rect => Rect::from_center_size(position, size).contains(point),
+            CollisionBody::Circle => position.distance(point) <= size.x / 2.0,
+        })
+    }
+
+    pub fn rect_overlap(&self, area: Rect, mask: CollisionMask) -> Vec<Entity> {
+        self.overlap(mask, |position, size, body| match body {
+            CollisionBody::Rect => {
+                rect_rect_collision(area.center(), area.size(), position, size).is_some()
+            }
+            CollisionBody::Circle => {
+                rect_circle_collision(area.center(), area.size(), position, size).is_some()
+            }
+        })
+    }
+
+    pub fn circle_overlap(&self, center: Vec2, radius: f32, mask: CollisionMask) -> Vec<Entity> {
+        let circle_size = Vec2::splat(radius * 2.0);
+        self.overlap(mask, |position, size, body| match body {
+            CollisionBody::Rect => {
+                rect_circle_collision(position, size, center, circle_size).is_some()
+            }
+            CollisionBody::Circle => {
+                circle_circle_collision(center, circle_size, position, size).is_some()
+            }
+        })
+    }
+
+    /// `direction` has to be normalized, the intersections measure distances in its units
+    fn cast(
+        &self,
+        origin: Vec2,
+        direction: Vec2,
+        max_distance: f32,
+        mask: CollisionMask,
+        intersect: impl Fn(Vec2, Vec2, CollisionBody) -> Option<RayIntersection>,
+    ) -> Option<RayHit> {
+        self.bodies
+            .iter()
+            .filter(|(_, _, collision_properties)| mask.contains(&collision_properties.group))
+            .filter_map(|(entity, transform, collision_properties)| {
+                let intersection = intersect(
+                    transform.translation.truncate(),
+                    transform.scale.truncate(),
+                    collision_properties.body,
+                )?;
+                (intersection.distance <= max_distance).then_some(RayHit {
+                    entity,
+                    point: origin + direction * intersection.distance,
+                    normal: intersection.normal,
+                    distance: intersection.distance,
+                })
+            })
+            .min_by(|a, b| a.distance.total_cmp(&b.distance))
+    }
+
+    fn overlap(
+        &self,
+        mask: CollisionMask,
+        overlaps: impl Fn(Vec2, Vec2, CollisionBody) -> bool,
+    ) -> Vec<Entity> {
+        self.bodies
+            .iter()
+            .filter(|(_, transform, collision_properties)| {
+                mask.contains(&collision_properties.group)
+                    && overlaps(
+                        transform.translation.truncate(),
+                        transform.scale.truncate(),
+                        collision_properties.body,
+                    )
+            })
+            .map(|(entity, _, _)| entity)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use bevy::ecs::system::SystemState;
+    use rstest::rstest;
+
+    use crate::internal::physics::CollisionGroup;
+
+    fn world_with_bodies() -> World {
+        let mut world = World::new();
+        for (position, body) in [
+            (Vec2::new(0.0, 100.0), CollisionBody::Rect),
+            (Vec2::new(100.0, 0.0), CollisionBody::Circle),
+        ] {
+            world.spawn((
+                Transform::from_translation(position.extend(0.0))
+                    .with_scale(Vec3::new(20.0, 20.0, 1.0)),
+                CollisionProperties {
+                    body,
+                    group: CollisionMask::new(&[CollisionGroup::Block]),
+                    ..default()
+                },
+            ));
+        }
+        world
+    }
+
+    #[rstest]
+    #[case(Vec2::Y)]
+    #[case(Vec2::X)]
+    fn test_cast_direction_length_does_not_matter(#[case] direction: Vec2) {
+        let mut world = world_with_bodies();
+        let mut state = SystemState::<SpatialQuery>::new(&mut world);
+        let spatial_query = state.get(&world);
+        let mask = CollisionMask::new(&[CollisionGroup::Block]);
+
+        let ray_hit = spatial_query.ray_cast(Vec2::ZERO, direction, 95.0, mask);
+        let long_ray_hit = spatial_query.ray_cast(Vec2::ZERO, direction * 5.0, 95.0, mask);
+        let circle_hit = spatial_query.circle_cast(Vec2::ZERO, 5.0, direction, 95.0, mask);
+        let long_circle_hit =
+            spatial_query.circle_cast(Vec2::ZERO, 5.0, direction * 5.0, 95.0, mask);
+
+        let ray_hit = ray_hit.unwrap();
+        assert!((ray_hit.distance - 90.0).abs() < 1e-3, "{ray_hit:?}");
+        assert!(
+            ray_hit.point.abs_diff_eq(direction * 90.0, 1e-3),
+            "{ray_hit:?}"
+        );
+        assert_eq!(long_ray_hit, Some(ray_hit));
+        let circle_hit = circle_hit.unwrap();
+        assert!((circle_hit.distance - 85.0).abs() < 1e-3, "{circle_hit:?}");
+        assert_eq!(long_circle_hit, Some(circle_hit));
+        assert_eq!(
+            spatial_query.ray_cast(Vec2::ZERO, direction * 5.0, 80.0, mask),
+            None
+        );
+    }
+}
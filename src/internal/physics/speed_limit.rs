@@ -0,0 +1,50 @@
+use bevy::prelude::*;
+
+use super::{RigidBody, Velocity};
+
+/// Keeps the speed of a body between `min` and `max` after every physics step, kinematic bodies are left alone
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct SpeedLimit {
+    pub min: f32,
+    pub max: f32,
+}
+
+impl SpeedLimit {
+    pub fn clamp(&self, velocity: Vec2) -> Vec2 {
+        // A body at rest has no direction to speed up in
+        if velocity == Vec2::ZERO {
+            return velocity;
+        }
+        velocity.clamp_length(self.min, self.max)
+    }
+}
+
+pub fn apply_speed_limit(mut query: Query<(&mut Velocity, &SpeedLimit, Option<&RigidBody>)>) {
+    for (mut velocity, speed_limit, rigid_body) in &mut query {
+        if rigid_body == Some(&RigidBody::Kinematic) {
+            continue;
+        }
+        velocity.0 = speed_limit.clamp(velocity.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(Vec2::new(0.0, 200.0), Vec2::new(0.0, 200.0))]
+    #[case(Vec2::new(0.0, 50.0), Vec2::new(0.0, 100.0))]
+    #[case(Vec2::new(-600.0, 0.0), Vec2::new(-400.0, 0.0))]
+    #[case(Vec2::ZERO, Vec2::ZERO)]
+    fn test_speed_limit_clamp(#[case] velocity: Vec2, #[case] expected: Vec2) {
+        let speed_limit = SpeedLimit {
+            min: 100.0,
+            max: 400.0,
+        };
+
+        assert_eq!(speed_limit.clamp(velocity), expected);
+    }
+}
@@ -0,0 +1,132 @@
+use bevy::prelude::*;
+
+use super::{local_time_scale, GameTime, LocalTimeScale, PhysicsConfig, Velocity};
+
+/// Radians per second, counter-clockwise
+#[derive(Component, Default, Deref, DerefMut)]
+pub struct AngularVelocity(pub f32);
+
+/**
+ * Makes spin bend the direction of travel over time.
+ * `curve` is the turn rate of the velocity per unit of angular velocity,
+ * `damping` the fraction of spin lost per second.
+ */
+#[derive(Component)]
+pub struct SpinEffect {
+    pub curve: f32,
+    pub damping: f32,
+}
+
+pub fn apply_angular_velocity(
+    mut query: Query<(&mut Transform, &AngularVelocity, Option<&LocalTimeScale>)>,
+    time: GameTime,
+    config: Res<PhysicsConfig>,
+) {
+    let substep_seconds = config.substep_seconds(time.delta_seconds());
+    for (mut transform, angular_velocity, time_scale) in &mut query {
+        let delta_seconds = substep_seconds * local_time_scale(time_scale);
+        transform.rotate_z(angular_velocity.0 * delta_seconds);
+    }
+}
+
+pub fn apply_spin_effect(
+    mut query: Query<(
+        &mut Velocity,
+        &mut AngularVelocity,
+        &SpinEffect,
+        Option<&LocalTimeScale>,
+    )>,
+    time: GameTime,
+    config: Res<PhysicsConfig>,
+) {
+    let substep_seconds = config.substep_seconds(time.delta_seconds());
+    for (mut velocity, mut angular_velocity, spin_effect, time_scale) in &mut query {
+        let delta_seconds = substep_seconds * local_time_scale(time_scale);
+        velocity.0 = Vec2::from_angle(angular_velocity.0 * spin_effect.curve * delta_seconds)
+            .rotate(velocity.0);
+        angular_velocity.0 *= (1.0 - spin_effect.damping * delta_seconds).max(0.0);
+    }
+}
+
+/**
+ * Applies a tangential friction impulse to a spinning disc touching a surface.
+ * Removes `friction` of the slip at the contact point, split between linear and
+ * angular velocity like a solid disc would (one third linear, two thirds angular).
+ */
+pub fn contact_friction(
+    velocity: Vec2,
+    angular_velocity: f32,
+    radius: f32,
+    normal: Vec2,
+    surface_velocity: Vec2,
+    friction: f32,
+) -> (Vec2, f32) {
+    let tangent = normal.perp();
+    let contact_speed = velocity.dot(tangent) - angular_velocity * radius;
+    let slip = contact_speed - surface_velocity.dot(tangent);
+    let correction = slip * friction.clamp(0.0, 1.0);
+
+    let velocity = velocity - tangent * (correction / 3.0);
+    let angular_velocity = angular_velocity + correction * 2.0 / 3.0 / radius;
+    (velocity, angular_velocity)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(
+        Vec2::new(0.0, 100.0),
+        0.0,
+        Vec2::new(0.0, 1.0),
+        Vec2::ZERO,
+        1.0,
+        (Vec2::new(0.0, 100.0), 0.0)
+    )]
+    #[case(
+        Vec2::new(0.0, 100.0),
+        0.0,
+        Vec2::new(0.0, 1.0),
+        Vec2::new(300.0, 0.0),
+        0.0,
+        (Vec2::new(0.0, 100.0), 0.0)
+    )]
+    #[case(
+        Vec2::new(0.0, 100.0),
+        0.0,
+        Vec2::new(0.0, 1.0),
+        Vec2::new(300.0, 0.0),
+        1.0,
+        (Vec2::new(100.0, 100.0), 20.0)
+    )]
+    #[case(
+        Vec2::new(30.0, 100.0),
+        -3.0,
+        Vec2::new(0.0, 1.0),
+        Vec2::ZERO,
+        1.0,
+        (Vec2::new(30.0, 100.0), -3.0)
+    )]
+    fn test_contact_friction(
+        #[case] velocity: Vec2,
+        #[case] angular_velocity: f32,
+        #[case] normal: Vec2,
+        #[case] surface_velocity: Vec2,
+        #[case] friction: f32,
+        #[case] expected: (Vec2, f32),
+    ) {
+        let result = contact_friction(
+            velocity,
+            angular_velocity,
+            10.0,
+            normal,
+            surface_velocity,
+            friction,
+        );
+
+        assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,64 @@
+use std::time::Duration;
+
+use bevy::{ecs::system::SystemParam, prelude::*};
+
+/**
+ * Speed of game time relative to the `FixedUpdate` clock, 1 by default.
+ * The tick rate stays the same, so input and rendering stay responsive while the game slows down.
+ */
+#[derive(Resource, Debug, Clone, Copy, Deref, DerefMut)]
+pub struct TimeScale(pub f32);
+
+impl Default for TimeScale {
+    fn default() -> Self {
+        Self(1.0)
+    }
+}
+
+/// Multiplies the game time of a single body on top of the global `TimeScale`
+#[derive(Component, Debug, Clone, Copy, Deref, DerefMut)]
+pub struct LocalTimeScale(pub f32);
+
+pub fn local_time_scale(local_time_scale: Option<&LocalTimeScale>) -> f32 {
+    local_time_scale.map_or(1.0, |local_time_scale| local_time_scale.0)
+}
+
+/// Moves a body on real time, ignoring `TimeScale`, for kinematic bodies driven by player input
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct UnscaledTime;
+
+/// Game clock, `Time` scaled by `TimeScale`
+#[derive(SystemParam)]
+pub struct GameTime<'w> {
+    time: Res<'w, Time>,
+    time_scale: Res<'w, TimeScale>,
+}
+
+impl GameTime<'_> {
+    pub fn delta(&self) -> Duration {
+        self.time.delta().mul_f32(self.time_scale.0)
+    }
+
+    pub fn delta_seconds(&self) -> f32 {
+        self.time.delta_seconds() * self.time_scale.0
+    }
+
+    /// Game time of the tick for a single body, taking `LocalTimeScale` and `UnscaledTime` into account
+    pub fn body_delta_seconds(
+        &self,
+        time_scale: Option<&LocalTimeScale>,
+        unscaled_time: Option<&UnscaledTime>,
+    ) -> f32 {
+        let delta_seconds = if unscaled_time.is_some() {
+            self.unscaled_delta_seconds()
+        } else {
+            self.delta_seconds()
+        };
+        delta_seconds * local_time_scale(time_scale)
+    }
+
+    /// Real time of the tick, for whatever has to keep up with the player
+    pub fn unscaled_delta_seconds(&self) -> f32 {
+        self.time.delta_seconds()
+    }
+}
@@ -1,11 +1,24 @@
 use bevy::prelude::*;
 
+use super::{GameTime, LocalTimeScale, PhysicsConfig, UnscaledTime};
+
 #[derive(Component, Deref, DerefMut)]
 pub struct Velocity(pub Vec2);
 
-pub fn apply_velocity(mut query: Query<(&mut Transform, &Velocity)>, time: Res<Time>) {
-    for (mut transform, velocity) in &mut query {
-        transform.translation.x += velocity.x * time.delta_seconds();
-        transform.translation.y += velocity.y * time.delta_seconds();
+pub fn apply_velocity(
+    mut query: Query<(
+        &mut Transform,
+        &Velocity,
+        Option<&LocalTimeScale>,
+        Option<&UnscaledTime>,
+    )>,
+    time: GameTime,
+    config: Res<PhysicsConfig>,
+) {
+    for (mut transform, velocity, time_scale, unscaled_time) in &mut query {
+        let delta_seconds =
+            config.substep_seconds(time.body_delta_seconds(time_scale, unscaled_time));
+        transform.translation.x += velocity.x * delta_seconds;
+        transform.translation.y += velocity.y * delta_seconds;
     }
 }
@@ -0,0 +1,167 @@
+use bevy::{
+    audio::{AddAudioSource, AudioSourceBundle, Volume},
+    prelude::*,
+    utils::HashMap,
+};
+
+use super::synth::{SynthSound, Tone, Waveform};
+
+const MIN_SPEED: f32 = 0.5;
+const MAX_SPEED: f32 = 2.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SoundKind {
+    Paddle,
+    Wall,
+    BrickHit,
+    BrickBreak,
+    PowerUp,
+    BallLost,
+}
+
+impl SoundKind {
+    pub const ALL: [SoundKind; 6] = [
+        SoundKind::Paddle,
+        SoundKind::Wall,
+        SoundKind::BrickHit,
+        SoundKind::BrickBreak,
+        SoundKind::PowerUp,
+        SoundKind::BallLost,
+    ];
+
+    pub fn tone(&self) -> Tone {
+        match self {
+            SoundKind::Paddle => Tone {
+                waveform: Waveform::Square,
+                start_frequency: 220.0,
+                end_frequency: 180.0,
+                duration_seconds: 0.12,
+                attack_seconds: 0.002,
+                decay: 25.0,
+                volume: 0.3,
+            },
+            SoundKind::Wall => Tone {
+                waveform: Waveform::Triangle,
+                start_frequency: 330.0,
+                end_frequency: 300.0,
+                duration_seconds: 0.08,
+                attack_seconds: 0.002,
+                decay: 35.0,
+                volume: 0.4,
+            },
+            SoundKind::BrickHit => Tone {
+                waveform: Waveform::Sine,
+                start_frequency: 660.0,
+                end_frequency: 620.0,
+                duration_seconds: 0.1,
+                attack_seconds: 0.002,
+                decay: 30.0,
+                volume: 0.5,
+            },
+            SoundKind::BrickBreak => Tone {
+                waveform: Waveform::Noise,
+                start_frequency: 0.0,
+                end_frequency: 0.0,
+                duration_seconds: 0.2,
+                attack_seconds: 0.001,
+                decay: 18.0,
+                volume: 0.35,
+            },
+            SoundKind::PowerUp => Tone {
+                waveform: Waveform::Triangle,
+                start_frequency: 440.0,
+                end_frequency: 1320.0,
+                duration_seconds: 0.3,
+                attack_seconds: 0.01,
+                decay: 4.0,
+                volume: 0.5,
+            },
+            SoundKind::BallLost => Tone {
+                waveform: Waveform::Square,
+                start_frequency: 330.0,
+                end_frequency: 80.0,
+                duration_seconds: 0.6,
+                attack_seconds: 0.01,
+                decay: 3.0,
+                volume: 0.3,
+            },
+        }
+    }
+}
+
+/// Plays a sound, `speed` scales its playback speed and pitch
+#[derive(Event, Debug, Clone, Copy)]
+pub struct PlaySound {
+    pub kind: SoundKind,
+    pub speed: f32,
+}
+
+impl From<SoundKind> for PlaySound {
+    fn from(kind: SoundKind) -> Self {
+        Self { kind, speed: 1.0 }
+    }
+}
+
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct AudioSettings {
+    pub volume: f32,
+    pub muted: bool,
+}
+
+impl Default for AudioSettings {
+    fn default() -> Self {
+        Self {
+            volume: 0.8,
+            muted: false,
+        }
+    }
+}
+
+/// Sounds synthesized at startup
+#[derive(Resource, Default)]
+pub struct Sounds(HashMap<SoundKind, Handle<SynthSound>>);
+
+/// Synthesized sound effects, played by sending `PlaySound` events
+pub struct SoundPlugin;
+
+impl Plugin for SoundPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_audio_source::<SynthSound>()
+            .add_event::<PlaySound>()
+            .init_resource::<AudioSettings>()
+            .init_resource::<Sounds>()
+            .add_systems(Startup, synthesize_sounds)
+            .add_systems(Update, play_sounds);
+    }
+}
+
+fn synthesize_sounds(mut sounds: ResMut<Sounds>, mut assets: ResMut<Assets<SynthSound>>) {
+    for kind in SoundKind::ALL {
+        sounds
+            .0
+            .insert(kind, assets.add(SynthSound::from(kind.tone())));
+    }
+}
+
+fn play_sounds(
+    mut commands: Commands,
+    mut ev_sound: EventReader<PlaySound>,
+    sounds: Res<Sounds>,
+    settings: Res<AudioSettings>,
+) {
+    if settings.muted || settings.volume <= 0.0 {
+        ev_sound.clear();
+        return;
+    }
+    for sound in ev_sound.read() {
+        let Some(source) = sounds.0.get(&sound.kind) else {
+            continue;
+        };
+        commands.spawn(AudioSourceBundle {
+            source: source.clone(),
+            settings: PlaybackSettings::DESPAWN
+                .with_volume(Volume::new_relative(settings.volume))
+                .with_speed(sound.speed.clamp(MIN_SPEED, MAX_SPEED)),
+        });
+    }
+}
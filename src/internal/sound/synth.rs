@@ -0,0 +1,189 @@
+use std::{f32::consts::TAU, sync::Arc, time::Duration};
+
+use bevy::{
+    audio::{Decodable, Source},
+    prelude::*,
+    reflect::TypePath,
+};
+
+pub const SAMPLE_RATE: u32 = 44_100;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Waveform {
+    Sine,
+    Square,
+    Triangle,
+    Noise,
+}
+
+/**
+ * Description of a short synthesized sound.
+ * The frequency sweeps from `start_frequency` to `end_frequency`,
+ * the amplitude rises over `attack_seconds` and then decays exponentially.
+ */
+#[derive(Debug, Clone, Copy)]
+pub struct Tone {
+    pub waveform: Waveform,
+    pub start_frequency: f32,
+    pub end_frequency: f32,
+    pub duration_seconds: f32,
+    pub attack_seconds: f32,
+    /// Exponential decay rate of the amplitude per second
+    pub decay: f32,
+    pub volume: f32,
+}
+
+impl Tone {
+    pub fn synthesize(&self) -> Vec<f32> {
+        let sample_count = (self.duration_seconds * SAMPLE_RATE as f32) as usize;
+        let mut phase = 0.0f32;
+        // xorshift, the noise only needs to sound random
+        let mut noise_state = 0x2545_F491u32;
+
+        (0..sample_count)
+            .map(|index| {
+                let seconds = index as f32 / SAMPLE_RATE as f32;
+                let progress = index as f32 / sample_count as f32;
+                let frequency =
+                    self.start_frequency + (self.end_frequency - self.start_frequency) * progress;
+                phase = (phase + frequency / SAMPLE_RATE as f32).fract();
+
+                let value = match self.waveform {
+                    Waveform::Sine => (phase * TAU).sin(),
+                    Waveform::Square => {
+                        if phase < 0.5 {
+                            1.0
+                        } else {
+                            -1.0
+                        }
+                    }
+                    Waveform::Triangle => 1.0 - 4.0 * (phase - 0.5).abs(),
+                    Waveform::Noise => {
+                        noise_state ^= noise_state << 13;
+                        noise_state ^= noise_state >> 17;
+                        noise_state ^= noise_state << 5;
+                        noise_state as f32 / u32::MAX as f32 * 2.0 - 1.0
+                    }
+                };
+
+                let attack = if self.attack_seconds > 0.0 {
+                    (seconds / self.attack_seconds).min(1.0)
+                } else {
+                    1.0
+                };
+                // Fade out the last samples, so the sound doesn't end with a click
+                let release = ((1.0 - progress) * 20.0).min(1.0);
+                value * self.volume * attack * release * (-self.decay * seconds).exp()
+            })
+            .collect()
+    }
+}
+
+/// Mono sound synthesized into memory
+#[derive(Asset, TypePath, Debug, Clone)]
+pub struct SynthSound {
+    samples: Arc<[f32]>,
+}
+
+impl SynthSound {
+    pub fn new(samples: Vec<f32>) -> Self {
+        Self {
+            samples: samples.into(),
+        }
+    }
+}
+
+impl From<Tone> for SynthSound {
+    fn from(tone: Tone) -> Self {
+        Self::new(tone.synthesize())
+    }
+}
+
+pub struct SynthDecoder {
+    samples: Arc<[f32]>,
+    position: usize,
+}
+
+impl Iterator for SynthDecoder {
+    type Item = f32;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let sample = self.samples.get(self.position).copied();
+        self.position += 1;
+        sample
+    }
+}
+
+impl Source for SynthDecoder {
+    fn current_frame_len(&self) -> Option<usize> {
+        Some(self.samples.len().saturating_sub(self.position))
+    }
+
+    fn channels(&self) -> u16 {
+        1
+    }
+
+    fn sample_rate(&self) -> u32 {
+        SAMPLE_RATE
+    }
+
+    fn total_duration(&self) -> Option<Duration> {
+        Some(Duration::from_secs_f32(
+            self.samples.len() as f32 / SAMPLE_RATE as f32,
+        ))
+    }
+}
+
+impl Decodable for SynthSound {
+    type DecoderItem = f32;
+    type Decoder = SynthDecoder;
+
+    fn decoder(&self) -> Self::Decoder {
+        SynthDecoder {
+            samples: self.samples.clone(),
+            position: 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(Waveform::Sine)]
+    #[case(Waveform::Square)]
+    #[case(Waveform::Triangle)]
+    #[case(Waveform::Noise)]
+    fn test_synthesize(#[case] waveform: Waveform) {
+        let tone = Tone {
+            waveform,
+            start_frequency: 440.0,
+            end_frequency: 220.0,
+            duration_seconds: 0.1,
+            attack_seconds: 0.005,
+            decay: 10.0,
+            volume: 0.8,
+        };
+
+        let samples = tone.synthesize();
+
+        assert_eq!(samples.len(), (0.1 * SAMPLE_RATE as f32) as usize);
+        assert!(samples.iter().all(|sample| sample.abs() <= 0.8));
+        assert!(samples.iter().any(|sample| sample.abs() > 0.1));
+        assert!(samples.last().unwrap().abs() < 0.01);
+    }
+
+    #[test]
+    fn test_decoder_plays_all_samples() {
+        let sound = SynthSound::new(vec![0.1, 0.2, 0.3]);
+
+        let decoder = sound.decoder();
+
+        let duration_samples = decoder.total_duration().unwrap().as_secs_f32() * SAMPLE_RATE as f32;
+        assert!((duration_samples - 3.0).abs() < 1e-3, "{duration_samples}");
+        assert_eq!(decoder.collect::<Vec<_>>(), vec![0.1, 0.2, 0.3]);
+    }
+}
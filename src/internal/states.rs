@@ -6,4 +6,5 @@ pub enum AppState {
     MainMenu,
     Game,
     Paused,
+    LevelComplete,
 }
\ No newline at end of file
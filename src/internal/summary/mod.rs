@@ -0,0 +1 @@
+pub mod summary;
\ No newline at end of file
@@ -0,0 +1,113 @@
+use bevy::prelude::*;
+
+use crate::internal::{game::score::LevelResult, states::AppState};
+
+const TITLE_FONT_SIZE: f32 = 50.0;
+const LINE_FONT_SIZE: f32 = 30.0;
+const TITLE_COLOR: Color = Color::rgb(0.5, 0.5, 1.0);
+const LINE_COLOR: Color = Color::rgb(0.3, 0.3, 0.3);
+const TOTAL_COLOR: Color = Color::rgb(1.0, 0.5, 0.5);
+
+pub struct SummaryPlugin;
+
+#[derive(Component)]
+pub struct SummaryState;
+
+impl Plugin for SummaryPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(AppState::LevelComplete), summary_setup)
+            .add_systems(OnExit(AppState::LevelComplete), summary_teardown)
+            .add_systems(
+                Update,
+                space_to_menu.run_if(in_state(AppState::LevelComplete)),
+            );
+    }
+}
+
+fn summary_teardown(mut commands: Commands, query: Query<Entity, With<SummaryState>>) {
+    for entity in query.iter() {
+        // despawn the entity and its children
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+/// Lines of the score breakdown, label and points
+fn breakdown_lines(result: &LevelResult) -> Vec<(String, usize)> {
+    let scoreboard = &result.0;
+    let breakdown = &scoreboard.breakdown;
+    let elapsed_seconds = scoreboard.elapsed_seconds as usize;
+    vec![
+        ("Bricks".to_string(), breakdown.bricks),
+        (
+            format!("Combos (longest {})", scoreboard.longest_combo),
+            breakdown.combo_bonus,
+        ),
+        ("Chain reactions".to_string(), breakdown.chain_bonus),
+        (
+            format!(
+                "Time bonus ({}:{:02})",
+                elapsed_seconds / 60,
+                elapsed_seconds % 60
+            ),
+            breakdown.time_bonus,
+        ),
+        (
+            format!("No life lost ({} lost)", scoreboard.lives_lost),
+            breakdown.no_life_lost_bonus,
+        ),
+    ]
+}
+
+fn summary_setup(mut commands: Commands, result: Res<LevelResult>) {
+    let line_style = |color| TextStyle {
+        font_size: LINE_FONT_SIZE,
+        color,
+        ..default()
+    };
+
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    width: Val::Percent(100.),
+                    height: Val::Percent(100.),
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    justify_content: JustifyContent::Center,
+                    ..default()
+                },
+                ..default()
+            },
+            SummaryState,
+        ))
+        .with_children(|parent| {
+            parent.spawn(TextBundle::from_section(
+                "Level Complete",
+                TextStyle {
+                    font_size: TITLE_FONT_SIZE,
+                    color: TITLE_COLOR,
+                    ..default()
+                },
+            ));
+            for (label, points) in breakdown_lines(&result) {
+                parent.spawn(TextBundle::from_section(
+                    format!("{label}: {points}"),
+                    line_style(LINE_COLOR),
+                ));
+            }
+            parent.spawn(TextBundle::from_section(
+                format!("Total: {}", result.0.score()),
+                line_style(TOTAL_COLOR),
+            ));
+            parent.spawn(TextBundle::from_section(
+                "Press Space to continue",
+                line_style(LINE_COLOR),
+            ));
+        });
+}
+
+pub fn space_to_menu(mut next_state: ResMut<NextState<AppState>>, input: Res<Input<KeyCode>>) {
+    if input.just_pressed(KeyCode::Space) {
+        next_state.set(AppState::MainMenu);
+    }
+}
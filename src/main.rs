@@ -1,6 +1,6 @@
 use bevy::prelude::*;
 use breakout_bevy::internal::{
-    camera::CameraPlugin, game::game::GamePlugin, menu::menu::MenuPlugin, states::AppState, physics::PhysicsPlugin,
+    camera::CameraPlugin, effects::effects::EffectsPlugin, game::game::GamePlugin, menu::menu::MenuPlugin, states::AppState, physics::{PhysicsPlugin, PhysicsDebugPlugin}, summary::summary::SummaryPlugin, sound::sound::SoundPlugin,
 };
 
 const BACKGROUND_COLOR: Color = Color::rgb(0.9, 0.9, 0.9);
@@ -9,8 +9,12 @@ fn main() {
     App::new()
         .add_plugins(DefaultPlugins)
         .add_plugins(PhysicsPlugin)
+        .add_plugins(PhysicsDebugPlugin)
+        .add_plugins(EffectsPlugin)
+        .add_plugins(SoundPlugin)
         .add_plugins(GamePlugin)
         .add_plugins(MenuPlugin)
+        .add_plugins(SummaryPlugin)
         .add_plugins(CameraPlugin)
         .add_state::<AppState>()
         .insert_resource(ClearColor(BACKGROUND_COLOR))